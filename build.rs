@@ -0,0 +1,55 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * Collects every day module in `src/bin` so the all-days runner can call the solvers in-process.
+ */
+use std::{env, fs, path::Path};
+
+/// Matches `YYYY-NN.rs`.
+fn is_day_module(file_name: &str) -> bool {
+    file_name.len() == 10
+        && file_name.ends_with(".rs")
+        && file_name[..4].chars().all(|c| c.is_ascii_digit())
+        && &file_name[4..5] == "-"
+        && file_name[5..7].chars().all(|c| c.is_ascii_digit())
+}
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    let mut days: Vec<String> = fs::read_dir(&bin_dir)
+        .unwrap()
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|file_name| is_day_module(file_name))
+        .map(|file_name| file_name[..7].to_string())
+        .collect();
+    days.sort();
+
+    let mut modules = String::new();
+    let mut registrations = String::new();
+    for day in days.iter() {
+        let path = bin_dir.join(format!("{}.rs", day));
+        let module = format!("day{}", day.replace('-', "_"));
+        modules.push_str(&format!(
+            "#[cfg(not(test))]\n#[path = {:?}]\n#[allow(dead_code)]\nmod {};\n",
+            path.display().to_string(),
+            module
+        ));
+        registrations.push_str(&format!(
+            "    #[cfg(not(test))]\n    registry.register({}::Solver);\n",
+            module
+        ));
+    }
+
+    let generated = format!(
+        "{}\n#[allow(unused_mut)]\n#[allow(clippy::let_and_return)]\npub fn registry() -> advent_of_code::Registry {{\n    let mut registry = advent_of_code::Registry::new();\n{}    registry\n}}\n",
+        modules, registrations
+    );
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, generated).unwrap();
+}
@@ -0,0 +1,276 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * Known correct answers for real inputs, stored in `src/YYYY/answers/NN.txt` (part one on the
+ * first line, part two on the second, blank if unknown). Checked by `cargo verify`.
+ *
+ * Answers rejected by `cargo submit` are kept in `src/YYYY/answers/NN-wrong.txt` so they are
+ * never submitted twice.
+ */
+use std::{
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+};
+
+pub fn answers_path(year: u16, day: u8) -> PathBuf {
+    crate::get_year_dir("answers", year).join(format!("{:02}.txt", day))
+}
+
+pub fn wrong_guesses_path(year: u16, day: u8) -> PathBuf {
+    crate::get_year_dir("answers", year).join(format!("{:02}-wrong.txt", day))
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(contents: &str) -> Answers {
+        let mut lines = contents.lines().map(|line| {
+            let line = line.trim();
+            (!line.is_empty()).then(|| line.to_string())
+        });
+        Answers {
+            part_one: lines.next().flatten(),
+            part_two: lines.next().flatten(),
+        }
+    }
+
+    /// Extracts the answers aoc-cli leaves in a downloaded puzzle once a part is solved.
+    pub fn from_puzzle(puzzle: &str) -> Answers {
+        let mut answers = puzzle.lines().filter_map(|line| {
+            line.trim()
+                .strip_prefix("Your puzzle answer was `")
+                .and_then(|rest| rest.split('`').next())
+                .map(str::to_string)
+        });
+        Answers {
+            part_one: answers.next(),
+            part_two: answers.next(),
+        }
+    }
+
+    /// Loads the recorded answers at `path`.
+    pub fn load(path: &Path) -> io::Result<Answers> {
+        let contents = crate::read_if_exists(path)?;
+        Ok(contents
+            .map(|contents| Answers::parse(&contents))
+            .unwrap_or_default())
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        crate::write_creating_dirs(path, &self.to_string())
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        match part {
+            1 => self.part_one = Some(answer.to_string()),
+            2 => self.part_two = Some(answer.to_string()),
+            _ => panic!("invalid part: {}", part),
+        }
+    }
+
+    /// Fills in answers from `other`, keeping existing ones where `other` has none.
+    pub fn merge(&mut self, other: Answers) {
+        self.part_one = other.part_one.or(self.part_one.take());
+        self.part_two = other.part_two.or(self.part_two.take());
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.part_one.as_deref().unwrap_or_default())?;
+        writeln!(f, "{}", self.part_two.as_deref().unwrap_or_default())
+    }
+}
+
+/// Rejected submissions, one `PART<TAB>ANSWER<TAB>NOTE` line each, e.g. `1\t4210\ttoo high`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct WrongGuesses {
+    guesses: Vec<(u8, String, String)>,
+}
+
+impl WrongGuesses {
+    pub fn parse(contents: &str) -> WrongGuesses {
+        let guesses = contents
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(3, '\t');
+                let part = fields.next()?.trim().parse().ok()?;
+                let answer = fields.next()?.trim().to_string();
+                let note = fields.next().unwrap_or_default().trim().to_string();
+                Some((part, answer, note))
+            })
+            .collect();
+        WrongGuesses { guesses }
+    }
+
+    /// Loads the rejected guesses at `path`.
+    pub fn load(path: &Path) -> io::Result<WrongGuesses> {
+        let contents = crate::read_if_exists(path)?;
+        Ok(contents
+            .map(|contents| WrongGuesses::parse(&contents))
+            .unwrap_or_default())
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        crate::write_creating_dirs(path, &self.to_string())
+    }
+
+    /// The note recorded when `answer` was rejected for `part`, if it was.
+    pub fn find(&self, part: u8, answer: &str) -> Option<&str> {
+        self.guesses
+            .iter()
+            .find(|(p, a, _)| *p == part && a == answer)
+            .map(|(_, _, note)| note.as_str())
+    }
+
+    pub fn record(&mut self, part: u8, answer: &str, note: &str) {
+        if self.find(part, answer).is_none() {
+            self.guesses
+                .push((part, answer.to_string(), note.to_string()));
+        }
+    }
+}
+
+impl Display for WrongGuesses {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (part, answer, note) in self.guesses.iter() {
+            writeln!(f, "{}\t{}\t{}", part, answer, note)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// The solver returned an error instead of an answer.
+    Error(String),
+    Missing,
+}
+
+impl Verdict {
+    pub fn check(expected: Option<&str>, actual: Option<&str>) -> Verdict {
+        match (expected, actual) {
+            (None, _) => Verdict::Missing,
+            (Some(expected), Some(actual)) if expected == actual => Verdict::Pass,
+            (Some(expected), actual) => Verdict::Fail {
+                expected: expected.to_string(),
+                actual: actual.map(str::to_string),
+            },
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail {
+                expected,
+                actual: Some(actual),
+            } => write!(f, "FAIL (expected {}, got {})", expected, actual),
+            Verdict::Fail {
+                expected,
+                actual: None,
+            } => write!(f, "FAIL (expected {}, not solved)", expected),
+            Verdict::Error(error) => write!(f, "FAIL (error: {})", error),
+            Verdict::Missing => write!(f, "missing answer"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("64929\n193697\n");
+        assert_eq!(answers.get(1), Some("64929"));
+        assert_eq!(answers.get(2), Some("193697"));
+
+        let answers = Answers::parse("\n193697\n");
+        assert_eq!(answers.get(1), None);
+        assert_eq!(answers.get(2), Some("193697"));
+
+        assert_eq!(Answers::parse(""), Answers::default());
+    }
+
+    #[test]
+    fn test_to_string() {
+        let answers = Answers {
+            part_one: Some("1912".to_string()),
+            part_two: None,
+        };
+        assert_eq!(answers.to_string(), "1912\n\n");
+        assert_eq!(Answers::parse(&answers.to_string()), answers);
+    }
+
+    #[test]
+    fn test_from_puzzle() {
+        let puzzle = "*How many characters?*\n\nYour puzzle answer was `1912`.\n\n\\--- Part Two ---\nYour puzzle answer was `2122`.\n";
+        assert_eq!(
+            Answers::from_puzzle(puzzle),
+            Answers {
+                part_one: Some("1912".to_string()),
+                part_two: Some("2122".to_string()),
+            }
+        );
+        assert_eq!(Answers::from_puzzle("no answers yet"), Answers::default());
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut answers = Answers::parse("1\n2\n");
+        answers.merge(Answers::parse("\n3\n"));
+        assert_eq!(answers, Answers::parse("1\n3\n"));
+    }
+
+    #[test]
+    fn test_wrong_guesses() {
+        let mut guesses = WrongGuesses::parse("1\t4210\ttoo high\n");
+        assert_eq!(guesses.find(1, "4210"), Some("too high"));
+        assert_eq!(guesses.find(2, "4210"), None);
+
+        guesses.record(2, "ABC", "wrong");
+        guesses.record(2, "ABC", "wrong");
+        assert_eq!(guesses.to_string(), "1\t4210\ttoo high\n2\tABC\twrong\n");
+        assert_eq!(WrongGuesses::parse(&guesses.to_string()), guesses);
+    }
+
+    #[test]
+    fn test_verdict() {
+        assert_eq!(Verdict::check(Some("7"), Some("7")), Verdict::Pass);
+        assert_eq!(Verdict::check(None, Some("7")), Verdict::Missing);
+        assert_eq!(
+            Verdict::check(Some("7"), Some("8")).to_string(),
+            "FAIL (expected 7, got 8)"
+        );
+        assert_eq!(
+            Verdict::check(Some("7"), None).to_string(),
+            "FAIL (expected 7, not solved)"
+        );
+        assert_eq!(
+            Verdict::Error("invalid line: \"X\"".to_string()).to_string(),
+            "FAIL (error: invalid line: \"X\")"
+        );
+    }
+}
@@ -0,0 +1,626 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * Minimal Advent of Code client used by `cargo download`, `cargo read` and `cargo submit`.
+ * The session token is read from `AOC_SESSION`, `~/.adventofcode.session` or
+ * `~/.config/adventofcode.session` (the same files aoc-cli uses), and the server from
+ * `AOC_BASE_URL`, which defaults to https://adventofcode.com.
+ */
+use crate::answers::{self, Answers};
+use std::{
+    env,
+    fmt::Display,
+    fs::{self, create_dir_all},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!("advent_of_code/", env!("CARGO_PKG_VERSION"), " (rust)");
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    BadStatus(u16),
+    Transport(String),
+    IoError,
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session token found. Set AOC_SESSION or write it to ~/.adventofcode.session."
+            ),
+            AocClientError::BadStatus(status) => {
+                write!(f, "server responded with status {}.", status)
+            }
+            AocClientError::Transport(e) => write!(f, "could not reach server: {}", e),
+            AocClientError::IoError => write!(f, "could not write output files to file system."),
+        }
+    }
+}
+
+/// The server's verdict on a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submission {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// Submitted too soon after the previous attempt, with the remaining wait if given.
+    RateLimited(Option<String>),
+    AlreadySolved,
+    Unknown(String),
+}
+
+impl Submission {
+    /// Classifies the `<article>` of the page returned after posting an answer.
+    pub fn parse(html: &str) -> Submission {
+        let text = html_to_markdown(main_content(html));
+        if text.contains("That's the right answer") {
+            Submission::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("answer is too high") {
+                Submission::TooHigh
+            } else if text.contains("answer is too low") {
+                Submission::TooLow
+            } else {
+                Submission::Incorrect
+            }
+        } else if text.contains("You gave an answer too recently") {
+            let wait = text
+                .split("You have ")
+                .nth(1)
+                .and_then(|rest| rest.split(" left to wait").next())
+                .map(str::to_string);
+            Submission::RateLimited(wait)
+        } else if text.contains("You don't seem to be solving the right level") {
+            Submission::AlreadySolved
+        } else {
+            Submission::Unknown(text.trim().to_string())
+        }
+    }
+
+    /// Whether the answer itself was rejected, as opposed to not being checked.
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Submission::TooHigh | Submission::TooLow | Submission::Incorrect
+        )
+    }
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Submission::Correct => write!(f, "right"),
+            Submission::TooHigh => write!(f, "too high"),
+            Submission::TooLow => write!(f, "too low"),
+            Submission::Incorrect => write!(f, "wrong"),
+            Submission::RateLimited(Some(wait)) => {
+                write!(f, "rate limited, {} left to wait", wait)
+            }
+            Submission::RateLimited(None) => write!(f, "rate limited"),
+            Submission::AlreadySolved => write!(f, "already solved"),
+            Submission::Unknown(text) => write!(f, "unrecognized response: {}", text),
+        }
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    year: u16,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str, year: u16) -> AocClient {
+        AocClient {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            year,
+        }
+    }
+
+    /// Builds a client from the environment, defaulting to [`crate::default_year`].
+    pub fn from_env(year: Option<u16>) -> Result<AocClient, AocClientError> {
+        let session = find_session().ok_or(AocClientError::SessionNotFound)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(AocClient::new(
+            &base_url,
+            &session,
+            year.unwrap_or_else(crate::default_year),
+        ))
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    fn request(&self, method: &str, path: &str) -> ureq::Request {
+        let url = format!("{}/{}/day/{}", self.base_url, self.year, path);
+        if cfg!(debug_assertions) {
+            println!("{} {}", method, url);
+        }
+
+        ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .build()
+            .request(method, &url)
+            .set("Cookie", &format!("session={}", self.session))
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocClientError> {
+        read_response(self.request("GET", path).call())
+    }
+
+    pub fn get_input(&self, day: u8) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", day))
+    }
+
+    /// Fetches the puzzle description, converted to markdown.
+    pub fn get_puzzle(&self, day: u8) -> Result<String, AocClientError> {
+        let html = self.get(&day.to_string())?;
+        Ok(html_to_markdown(main_content(&html)))
+    }
+
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Submission, AocClientError> {
+        let level = part.to_string();
+        let response = self
+            .request("POST", &format!("{}/answer", day))
+            .send_form(&[("level", &level), ("answer", answer)]);
+        Ok(Submission::parse(&read_response(response)?))
+    }
+
+    /// Writes the input, puzzle and any answers shown in the puzzle for `day`.
+    pub fn download(&self, day: u8) -> Result<(), AocClientError> {
+        let input = self.get_input(day)?;
+        let puzzle = self.get_puzzle(day)?;
+
+        let input_path = get_input_path(self.year, day);
+        let puzzle_path = get_puzzle_path(self.year, day);
+        write_file(&input_path, &input)?;
+        write_file(&puzzle_path, &puzzle)?;
+
+        println!("---");
+        println!(
+            "🎄 Successfully wrote input to \"{}\".",
+            input_path.display()
+        );
+        println!(
+            "🎄 Successfully wrote puzzle to \"{}\".",
+            puzzle_path.display()
+        );
+
+        let found = Answers::from_puzzle(&puzzle);
+        if found != Answers::default() {
+            let answers_path = answers::answers_path(self.year, day);
+            let mut answers = Answers::load(&answers_path).map_err(|_| AocClientError::IoError)?;
+            answers.merge(found);
+            answers
+                .save(&answers_path)
+                .map_err(|_| AocClientError::IoError)?;
+            println!(
+                "🎄 Successfully wrote answers to \"{}\".",
+                answers_path.display()
+            );
+        }
+
+        Ok(())
+    }
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, AocClientError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| AocClientError::Transport(e.to_string())),
+        Err(ureq::Error::Status(status, _)) => Err(AocClientError::BadStatus(status)),
+        Err(e) => Err(AocClientError::Transport(e.to_string())),
+    }
+}
+
+fn get_input_path(year: u16, day: u8) -> PathBuf {
+    crate::get_year_dir("inputs", year).join(format!("{:02}.txt", day))
+}
+
+pub fn get_puzzle_path(year: u16, day: u8) -> PathBuf {
+    crate::get_year_dir("puzzles", year).join(format!("{:02}.md", day))
+}
+
+/// Extracts the title from a downloaded puzzle, e.g. `Day 6: Tuning Trouble`.
+pub fn puzzle_title(puzzle: &str) -> Option<String> {
+    puzzle.lines().find_map(|line| {
+        line.trim_start_matches('\\')
+            .strip_prefix("--- ")?
+            .strip_suffix(" ---")
+            .filter(|title| title.starts_with("Day "))
+            .map(str::to_string)
+    })
+}
+
+/// The contents of the first code block in a downloaded puzzle, which is usually the first
+/// example.
+pub fn first_example(puzzle: &str) -> Option<String> {
+    let mut lines = puzzle.lines().skip_while(|line| *line != "```").skip(1);
+    let mut example = String::new();
+    for line in lines.by_ref() {
+        if line == "```" {
+            return Some(example).filter(|example| !example.is_empty());
+        }
+        example.push_str(line);
+        example.push('\n');
+    }
+    None
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), AocClientError> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent).map_err(|_| AocClientError::IoError)?;
+    }
+    fs::write(path, contents).map_err(|_| AocClientError::IoError)
+}
+
+fn find_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Some(session);
+        }
+    }
+
+    let home = env::var("HOME").ok()?;
+    [".adventofcode.session", ".config/adventofcode.session"]
+        .iter()
+        .filter_map(|file| fs::read_to_string(Path::new(&home).join(file)).ok())
+        .find(|session| !session.trim().is_empty())
+}
+
+/// The latest event that has started, in the puzzle unlock timezone (UTC-5).
+pub fn current_event_year() -> u16 {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default();
+    let (year, month) = year_month_from_days((secs - 5 * 3600).div_euclid(86400));
+    if month == 12 {
+        year as u16
+    } else {
+        year as u16 - 1
+    }
+}
+
+/// Converts days since the unix epoch to a (year, month) civil date.
+/// see: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn year_month_from_days(days: i64) -> (i64, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month)
+}
+
+/// The puzzle page's `<main>` element, or the whole page if it has none.
+fn main_content(html: &str) -> &str {
+    match (html.find("<main>"), html.rfind("</main>")) {
+        (Some(start), Some(end)) if start < end => &html[start + "<main>".len()..end],
+        _ => html,
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn tag_attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{}=\"", name))? + name.len() + 2;
+    let end = start + tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..end]))
+}
+
+/// Converts the subset of HTML used in puzzle descriptions to markdown, in the same style
+/// aoc-cli produces for `src/YYYY/puzzles`.
+fn html_to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut links: Vec<String> = Vec::new();
+    let mut in_pre = false;
+    let mut in_heading = false;
+    let mut skip_depth = 0;
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let (text, tag) = match rest.find('<') {
+            Some(0) => {
+                let end = rest.find('>').map(|i| i + 1).unwrap_or(rest.len());
+                let tag = &rest[..end];
+                rest = &rest[end..];
+                ("", Some(tag))
+            }
+            Some(i) => {
+                let text = &rest[..i];
+                rest = &rest[i..];
+                (text, None)
+            }
+            None => {
+                let text = rest;
+                rest = "";
+                (text, None)
+            }
+        };
+
+        if let Some(tag) = tag {
+            let closing = tag.starts_with("</");
+            let name: String = tag
+                .trim_start_matches(['<', '/'])
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric())
+                .collect();
+
+            if matches!(name.as_str(), "form" | "script" | "style") {
+                skip_depth = if closing {
+                    skip_depth - 1
+                } else {
+                    skip_depth + 1
+                };
+                continue;
+            }
+            if skip_depth > 0 {
+                continue;
+            }
+
+            match (name.as_str(), closing) {
+                ("h2", false) => in_heading = true,
+                ("h2", true) => {
+                    in_heading = false;
+                    markdown.push_str("\n----------\n\n");
+                }
+                ("p", true) => markdown.push_str("\n\n"),
+                ("pre", false) => {
+                    in_pre = true;
+                    markdown.push_str("```\n");
+                }
+                ("pre", true) => {
+                    in_pre = false;
+                    if !markdown.ends_with('\n') {
+                        markdown.push('\n');
+                    }
+                    markdown.push_str("```\n\n");
+                }
+                ("code", _) if !in_pre => markdown.push('`'),
+                ("em", _) if !in_pre => markdown.push('*'),
+                ("li", false) => markdown.push_str("* "),
+                ("li", true) => markdown.push('\n'),
+                ("ul", true) => markdown.push('\n'),
+                ("br", _) => markdown.push('\n'),
+                ("a", false) => {
+                    links.push(tag_attribute(tag, "href").unwrap_or_default());
+                    markdown.push('[');
+                }
+                ("a", true) => {
+                    let href = links.pop().unwrap_or_default();
+                    markdown.push_str(&format!("]({})", href));
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        if skip_depth > 0 {
+            continue;
+        }
+
+        let text = decode_entities(text);
+        if in_pre {
+            markdown.push_str(&text);
+            continue;
+        }
+
+        let mut collapsed = String::with_capacity(text.len());
+        for c in text.chars() {
+            if !c.is_whitespace() {
+                collapsed.push(c);
+            } else if !collapsed.ends_with(' ') {
+                collapsed.push(' ');
+            }
+        }
+        let at_line_start = markdown.is_empty() || markdown.ends_with('\n');
+        let mut text = if at_line_start {
+            collapsed.trim_start().to_string()
+        } else {
+            collapsed
+        };
+        if text.is_empty() {
+            continue;
+        }
+        if at_line_start && text.starts_with("---") {
+            text.insert(0, '\\');
+        }
+        if !in_heading {
+            text = text.replace('*', "\\*");
+        }
+        markdown.push_str(&text);
+    }
+
+    let mut normalized = String::new();
+    for line in markdown.lines() {
+        let line = line.trim_end();
+        if line.is_empty() && (normalized.is_empty() || normalized.ends_with("\n\n")) {
+            continue;
+        }
+        normalized.push_str(line);
+        normalized.push('\n');
+    }
+    normalized.trim_end().to_string() + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serves a single canned response, returning the base URL and a handle yielding the
+    /// request's head lines followed by its body.
+    fn mock_server(status: &str, body: &str) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request: Vec<String> = reader
+                .by_ref()
+                .lines()
+                .map(|line| line.unwrap())
+                .take_while(|line| !line.is_empty())
+                .collect();
+
+            let content_length = request
+                .iter()
+                .find_map(|line| line.strip_prefix("Content-Length: "))
+                .map(|length| length.parse().unwrap())
+                .unwrap_or(0);
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            request.push(String::from_utf8(body).unwrap());
+
+            stream.write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_get_input() {
+        let (base_url, server) = mock_server("200 OK", "1000\n2000\n");
+        let client = AocClient::new(&base_url, "secret\n", 2022);
+
+        assert_eq!(client.get_input(1).unwrap(), "1000\n2000\n");
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2022/day/1/input HTTP/1.1");
+        assert!(request.iter().any(|line| line == "Cookie: session=secret"));
+    }
+
+    #[test]
+    fn test_get_input_bad_status() {
+        let (base_url, server) = mock_server("400 Bad Request", "Please log in.");
+        let client = AocClient::new(&base_url, "expired", 2022);
+
+        assert!(matches!(
+            client.get_input(1),
+            Err(AocClientError::BadStatus(400))
+        ));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_get_puzzle() {
+        let page = "<html><body><main>\n<article class=\"day-desc\"><h2>--- Day 6: Tuning Trouble ---</h2><p>The signal is a <em>series</em> of <a href=\"/2016/day/6\" target=\"_blank\">characters</a>:</p>\n<pre><code>mjqjpqm&lt;b\n</code></pre>\n<ul>\n<li><code>bvwb</code>: after character <code><em>5</em></code></li>\n</ul>\n</article>\n<p>Your puzzle answer was <code>1912</code>.</p><p class=\"day-success\">It provides one gold star: *</p>\n<form method=\"post\"><input type=\"submit\" value=\"[Submit]\"/></form>\n</main></body></html>";
+        let (base_url, server) = mock_server("200 OK", page);
+        let client = AocClient::new(&base_url, "secret", 2022);
+
+        assert_eq!(
+            client.get_puzzle(6).unwrap(),
+            concat!(
+                "\\--- Day 6: Tuning Trouble ---\n",
+                "----------\n",
+                "\n",
+                "The signal is a *series* of [characters](/2016/day/6):\n",
+                "\n",
+                "```\n",
+                "mjqjpqm<b\n",
+                "```\n",
+                "\n",
+                "* `bvwb`: after character `*5*`\n",
+                "\n",
+                "Your puzzle answer was `1912`.\n",
+                "\n",
+                "It provides one gold star: \\*\n",
+            )
+        );
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_puzzle_title_and_example() {
+        let puzzle = "\\--- Day 6: Tuning Trouble ---\n----------\n\nThe signal:\n\n```\nmjqjpqm\nbvwb\n```\n\n```\nother\n```\n";
+        assert_eq!(
+            puzzle_title(puzzle),
+            Some("Day 6: Tuning Trouble".to_string())
+        );
+        assert_eq!(first_example(puzzle), Some("mjqjpqm\nbvwb\n".to_string()));
+        assert_eq!(puzzle_title("no title"), None);
+        assert_eq!(first_example("```\nunterminated\n"), None);
+    }
+
+    #[test]
+    fn test_submit() {
+        let page = "<main>\n<article><p>That's not the right answer; your answer is too high. If you're stuck, make sure you're using the full input data. <a href=\"/2022/day/6\">[Return to Day 6]</a></p></article>\n</main>";
+        let (base_url, server) = mock_server("200 OK", page);
+        let client = AocClient::new(&base_url, "secret", 2022);
+
+        assert_eq!(client.submit(6, 2, "4210").unwrap(), Submission::TooHigh);
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "POST /2022/day/6/answer HTTP/1.1");
+        assert_eq!(request.last().unwrap(), "level=2&answer=4210");
+    }
+
+    #[test]
+    fn test_parse_submission() {
+        assert_eq!(
+            Submission::parse("<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>"),
+            Submission::Correct
+        );
+        assert_eq!(
+            Submission::parse(
+                "<article><p>That's not the right answer; your answer is too low.</p></article>"
+            ),
+            Submission::TooLow
+        );
+        assert_eq!(
+            Submission::parse("<article><p>That's not the right answer. If you're stuck, make sure you're using the full input data.</p></article>"),
+            Submission::Incorrect
+        );
+        assert_eq!(
+            Submission::parse("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait.</p></article>"),
+            Submission::RateLimited(Some("34s".to_string()))
+        );
+        assert_eq!(
+            Submission::parse("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"),
+            Submission::AlreadySolved
+        );
+        assert!(Submission::TooHigh.is_wrong());
+        assert!(!Submission::RateLimited(None).is_wrong());
+    }
+
+    #[test]
+    fn test_year_month_from_days() {
+        assert_eq!(year_month_from_days(0), (1970, 1));
+        // 2022-12-01
+        assert_eq!(year_month_from_days(19327), (2022, 12));
+        // 2023-02-28
+        assert_eq!(year_month_from_days(19416), (2023, 2));
+    }
+}
@@ -0,0 +1,276 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * Repeated-run benchmarking for `cargo bench-day` / `cargo bench-all`.
+ */
+use crate::Answer;
+use std::collections::BTreeMap;
+use std::hint::black_box;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Where `--save-baseline` keeps a year's medians, e.g. `src/2022/benchmarks/baseline.csv`.
+pub fn baseline_path(year: u16) -> PathBuf {
+    crate::get_year_dir("benchmarks", year).join("baseline.csv")
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchOptions {
+    /// How long to run the solver before measuring.
+    pub warmup: Duration,
+    /// How long to keep measuring, within `min_runs..=max_runs`.
+    pub measurement: Duration,
+    pub min_runs: usize,
+    pub max_runs: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            warmup: Duration::from_millis(200),
+            measurement: Duration::from_secs(1),
+            min_runs: 10,
+            max_runs: 100_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub input_bytes: usize,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration], input_bytes: usize) -> Stats {
+        assert!(!samples.is_empty(), "need at least one sample");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let runs = sorted.len();
+
+        let median = if runs.is_multiple_of(2) {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        } else {
+            sorted[runs / 2]
+        };
+
+        let secs: Vec<f64> = sorted.iter().map(|d| d.as_secs_f64()).collect();
+        let mean = secs.iter().sum::<f64>() / runs as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / runs as f64;
+
+        Stats {
+            runs,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            input_bytes,
+        }
+    }
+
+    /// Bytes of input processed per second, based on the median run.
+    pub fn throughput(&self) -> f64 {
+        if self.median.is_zero() {
+            return f64::INFINITY;
+        }
+        self.input_bytes as f64 / self.median.as_secs_f64()
+    }
+}
+
+/// Formats a byte rate with a binary unit prefix, e.g. `12.34 MiB/s`.
+pub fn format_throughput(bytes_per_second: f64) -> String {
+    const UNITS: [&str; 4] = ["B/s", "KiB/s", "MiB/s", "GiB/s"];
+    let mut value = bytes_per_second;
+    let mut unit = 0;
+    while value >= 1024_f64 && unit < UNITS.len() - 1 {
+        value /= 1024_f64;
+        unit += 1;
+    }
+    format!("{:.2} {}", value, UNITS[unit])
+}
+
+/// Runs `solver` repeatedly on `input` and collects timing statistics.
+/// Returns `Ok(None)` without measuring if the solver does not produce an answer, and the
+/// solver's error if it fails.
+pub fn bench(
+    mut solver: impl FnMut(&str) -> Answer,
+    input: &str,
+    options: &BenchOptions,
+) -> Result<Option<Stats>, String> {
+    let warmup_start = Instant::now();
+    loop {
+        if black_box(solver(black_box(input)))?.is_none() {
+            return Ok(None);
+        }
+        if warmup_start.elapsed() >= options.warmup {
+            break;
+        }
+    }
+
+    let mut samples = Vec::new();
+    let measurement_start = Instant::now();
+    while samples.len() < options.max_runs
+        && (samples.len() < options.min_runs || measurement_start.elapsed() < options.measurement)
+    {
+        let timer = Instant::now();
+        let _ = black_box(solver(black_box(input)));
+        samples.push(timer.elapsed());
+    }
+
+    Ok(Some(Stats::from_samples(&samples, input.len())))
+}
+
+/// Median timings per day/part recorded by `--save-baseline`, stored as CSV.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Baseline {
+    medians: BTreeMap<(u8, u8), Duration>,
+}
+
+impl Baseline {
+    pub fn parse(contents: &str) -> Result<Baseline, String> {
+        let mut baseline = Baseline::default();
+        for (i, line) in contents.lines().enumerate().skip(1) {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let parsed = match fields[..] {
+                [day, part, median_ns] => day
+                    .parse()
+                    .ok()
+                    .zip(part.parse().ok())
+                    .zip(median_ns.parse().ok()),
+                _ => None,
+            };
+            match parsed {
+                Some(((day, part), median_ns)) => {
+                    baseline.insert(day, part, Duration::from_nanos(median_ns))
+                }
+                None => return Err(format!("invalid baseline entry on line {}", i + 1)),
+            }
+        }
+        Ok(baseline)
+    }
+
+    /// Loads the baseline at `path`, rejecting malformed entries as `InvalidData`.
+    pub fn load(path: &Path) -> io::Result<Baseline> {
+        match crate::read_if_exists(path)? {
+            Some(contents) => Baseline::parse(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            None => Ok(Baseline::default()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        crate::write_creating_dirs(path, &self.to_csv())
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("day,part,median_ns\n");
+        for ((day, part), median) in self.medians.iter() {
+            csv.push_str(&format!("{},{},{}\n", day, part, median.as_nanos()));
+        }
+        csv
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<Duration> {
+        self.medians.get(&(day, part)).copied()
+    }
+
+    pub fn insert(&mut self, day: u8, part: u8, median: Duration) {
+        self.medians.insert((day, part), median);
+    }
+}
+
+/// Relative change of `current` against `baseline`, in percent.
+pub fn change_percent(baseline: Duration, current: Duration) -> f64 {
+    if baseline.is_zero() {
+        return 0_f64;
+    }
+    (current.as_secs_f64() / baseline.as_secs_f64() - 1_f64) * 100_f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples: Vec<Duration> = [4, 1, 3, 2].map(Duration::from_millis).to_vec();
+        let stats = Stats::from_samples(&samples, 1000);
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean.as_micros(), 2500);
+        // population standard deviation of 1, 2, 3, 4 is sqrt(1.25)
+        assert_eq!(stats.stddev.as_micros(), 1118);
+        assert_eq!(stats.throughput().round(), 400_000_f64);
+    }
+
+    #[test]
+    fn test_format_throughput() {
+        assert_eq!(format_throughput(512_f64), "512.00 B/s");
+        assert_eq!(format_throughput(3_f64 * 1024_f64 * 1024_f64), "3.00 MiB/s");
+    }
+
+    #[test]
+    fn test_baseline_roundtrip() {
+        let mut baseline = Baseline::default();
+        baseline.insert(8, 2, Duration::from_micros(63));
+        baseline.insert(1, 1, Duration::from_nanos(4980));
+
+        let csv = baseline.to_csv();
+        assert_eq!(csv, "day,part,median_ns\n1,1,4980\n8,2,63000\n");
+        assert_eq!(Baseline::parse(&csv), Ok(baseline));
+    }
+
+    #[test]
+    fn test_baseline_parse_invalid() {
+        assert_eq!(
+            Baseline::parse("day,part,median_ns\n1,1,abc\n"),
+            Err("invalid baseline entry on line 2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_change_percent() {
+        let baseline = Duration::from_millis(10);
+        assert_eq!(
+            change_percent(baseline, Duration::from_millis(15)).round(),
+            50_f64
+        );
+        assert_eq!(
+            change_percent(baseline, Duration::from_millis(5)).round(),
+            -50_f64
+        );
+        assert_eq!(change_percent(Duration::ZERO, baseline), 0_f64);
+    }
+
+    #[test]
+    fn test_bench_unsolved() {
+        let options = BenchOptions {
+            warmup: Duration::ZERO,
+            measurement: Duration::ZERO,
+            min_runs: 3,
+            max_runs: 3,
+        };
+        assert_eq!(bench(|_| Ok(None), "abc", &options), Ok(None));
+        assert_eq!(
+            bench(|_| Err("bad input".to_string()), "abc", &options),
+            Err("bad input".to_string())
+        );
+
+        let stats = bench(|input| Ok(Some(input.len().to_string())), "abc", &options)
+            .unwrap()
+            .unwrap();
+        assert_eq!(stats.runs, 3);
+        assert_eq!(stats.input_bytes, 3);
+    }
+}
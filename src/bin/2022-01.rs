@@ -44,10 +44,17 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(first_three_sum)
 }
 
+advent_of_code::solution!(2022, 1);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 1);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let args = advent_of_code::DayArgs::from_env();
+    let input = &args.read_input(2022, 1);
+    if args.runs_part(1) {
+        advent_of_code::solve!(2022, 1, 1, part_one, input);
+    }
+    if args.runs_part(2) {
+        advent_of_code::solve!(2022, 1, 2, part_two, input);
+    }
 }
 
 #[cfg(test)]
@@ -56,13 +63,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 1);
+        let input = advent_of_code::read_file("examples", 2022, 1);
         assert_eq!(part_one(&input), Some(24000));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 1);
+        let input = advent_of_code::read_file("examples", 2022, 1);
         assert_eq!(part_two(&input), Some(45000));
     }
 }
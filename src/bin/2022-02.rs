@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-fn parse_input(input: &str) -> Vec<(String, String, String)> {
+fn parse_input(input: &str) -> Result<Vec<(String, String, String)>, String> {
     let opponent_hands = HashMap::from([("A", "rock"), ("B", "paper"), ("C", "scissors")]);
     let my_hands = HashMap::from([("X", "rock"), ("Y", "paper"), ("Z", "scissors")]);
     let results = HashMap::from([("X", "lose"), ("Y", "draw"), ("Z", "win")]);
@@ -13,13 +13,22 @@ fn parse_input(input: &str) -> Vec<(String, String, String)> {
     for line in lines {
         // get two String elements per line, spearated by a whitespace
         line_elements = line.split_whitespace().collect();
-        let opponent_hand = opponent_hands.get(line_elements[0]).unwrap().to_string();
-        let my_hand = my_hands.get(line_elements[1]).unwrap().to_string();
-        let result = results.get(line_elements[1]).unwrap().to_string();
+        let invalid_line = || format!("invalid line: \"{}\"", line);
+        let (opponent_code, my_code) = match line_elements[..] {
+            [opponent_code, my_code] => (opponent_code, my_code),
+            _ => return Err(invalid_line()),
+        };
+        let opponent_hand = opponent_hands.get(opponent_code).ok_or_else(invalid_line)?;
+        let my_hand = my_hands.get(my_code).ok_or_else(invalid_line)?;
+        let result = results.get(my_code).ok_or_else(invalid_line)?;
         // change to String
-        parsed_lines.push((opponent_hand, my_hand, result));
+        parsed_lines.push((
+            opponent_hand.to_string(),
+            my_hand.to_string(),
+            result.to_string(),
+        ));
     }
-    parsed_lines
+    Ok(parsed_lines)
 }
 
 fn rock_paper_scissors_winner(opponent_hand: &str, my_hand: &str) -> String {
@@ -73,31 +82,38 @@ fn score_hand(hand: &str) -> u32 {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let parsed_input = parse_input(input);
+pub fn part_one(input: &str) -> Result<u32, String> {
+    let parsed_input = parse_input(input)?;
     let mut total_score = 0;
     for (opponent_hand, my_hand, _) in parsed_input {
         total_score += score_play(&opponent_hand, &my_hand);
         total_score += score_hand(&my_hand);
     }
-    Some(total_score)
+    Ok(total_score)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let parsed_input = parse_input(input);
+pub fn part_two(input: &str) -> Result<u32, String> {
+    let parsed_input = parse_input(input)?;
     let mut total_score = 0;
     for (opponent_hand, _, result) in parsed_input {
         let my_hand = rock_paper_scissors_decisor(&opponent_hand, &result);
         total_score += score_play(&opponent_hand, &my_hand);
         total_score += score_hand(&my_hand);
     }
-    Some(total_score)
+    Ok(total_score)
 }
 
+advent_of_code::solution!(2022, 2);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let args = advent_of_code::DayArgs::from_env();
+    let input = &args.read_input(2022, 2);
+    if args.runs_part(1) {
+        advent_of_code::solve!(2022, 2, 1, part_one, input);
+    }
+    if args.runs_part(2) {
+        advent_of_code::solve!(2022, 2, 2, part_two, input);
+    }
 }
 
 #[cfg(test)]
@@ -106,13 +122,21 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_one(&input), Some(15));
+        let input = advent_of_code::read_file("examples", 2022, 2);
+        assert_eq!(part_one(&input), Ok(15));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_two(&input), None);
+        let input = advent_of_code::read_file("examples", 2022, 2);
+        assert_eq!(part_two(&input), Ok(12));
+    }
+
+    #[test]
+    fn test_invalid_line() {
+        assert_eq!(
+            part_one("A Y\nB Q\n"),
+            Err("invalid line: \"B Q\"".to_string())
+        );
     }
 }
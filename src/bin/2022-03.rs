@@ -13,28 +13,18 @@ fn parse_input(input: &str) -> Vec<(&str, &str)> {
 
 fn get_priority_score(item: &char) -> u32 {
     let priority_order = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
-    let priority_score = (priority_order.find(*item).unwrap() + 1) as u32;
-    priority_score
+
+    (priority_order.find(*item).unwrap() + 1) as u32
 }
 
 fn find_same_char(first: &str, second: &str) -> Option<char> {
-    for char in first.chars() {
-        // check if char in second
-        if second.contains(char) {
-            return Some(char);
-        }
-    }
-    None
+    first.chars().find(|&char| second.contains(char))
 }
 
 fn find_same_char_three(first: &str, second: &str, third: &str) -> Option<char> {
-    for char in first.chars() {
-        // check if char in second
-        if second.contains(char) && third.contains(char) {
-            return Some(char);
-        }
-    }
-    None
+    first
+        .chars()
+        .find(|&char| second.contains(char) && third.contains(char))
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
@@ -55,7 +45,7 @@ pub fn part_one(input: &str) -> Option<u32> {
 pub fn part_two(input: &str) -> Option<u32> {
     let parsed_input = parse_input(input);
     // make sure that the amount of rucksacks is a multiple of 3
-    assert!(parsed_input.len() % 3 == 0);
+    assert!(parsed_input.len().is_multiple_of(3));
     // join the rucksack compartments from (String, String) to concatenated String
     let parsed_input = parsed_input
         .iter()
@@ -84,10 +74,17 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(total_priority_score)
 }
 
+advent_of_code::solution!(2022, 3);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let args = advent_of_code::DayArgs::from_env();
+    let input = &args.read_input(2022, 3);
+    if args.runs_part(1) {
+        advent_of_code::solve!(2022, 3, 1, part_one, input);
+    }
+    if args.runs_part(2) {
+        advent_of_code::solve!(2022, 3, 2, part_two, input);
+    }
 }
 
 #[cfg(test)]
@@ -96,13 +93,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = advent_of_code::read_file("examples", 2022, 3);
         assert_eq!(part_one(&input), Some(157));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = advent_of_code::read_file("examples", 2022, 3);
         assert_eq!(part_two(&input), Some(70));
     }
 }
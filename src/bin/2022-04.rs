@@ -56,10 +56,17 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(overlaps)
 }
 
+advent_of_code::solution!(2022, 4);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 4);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let args = advent_of_code::DayArgs::from_env();
+    let input = &args.read_input(2022, 4);
+    if args.runs_part(1) {
+        advent_of_code::solve!(2022, 4, 1, part_one, input);
+    }
+    if args.runs_part(2) {
+        advent_of_code::solve!(2022, 4, 2, part_two, input);
+    }
 }
 
 #[cfg(test)]
@@ -68,13 +75,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 4);
+        let input = advent_of_code::read_file("examples", 2022, 4);
         assert_eq!(part_one(&input), Some(2));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 4);
+        let input = advent_of_code::read_file("examples", 2022, 4);
         assert_eq!(part_two(&input), Some(4));
     }
 }
@@ -1,4 +1,7 @@
-fn parse_input(input: &str) -> (Vec<Vec<char>>, Vec<(u32, u32, u32)>) {
+type Stacks = Vec<Vec<char>>;
+type Instruction = (u32, u32, u32);
+
+fn parse_input(input: &str) -> (Stacks, Vec<Instruction>) {
     let mut sections = input.split("\n\n");
 
     let initial_configurations = sections.next().unwrap().lines();
@@ -39,11 +42,8 @@ fn parse_input(input: &str) -> (Vec<Vec<char>>, Vec<(u32, u32, u32)>) {
     (stacks, instructions)
 }
 
-fn execute_instructions_one(
-    stacks: &Vec<Vec<char>>,
-    instructions: Vec<(u32, u32, u32)>,
-) -> Vec<Vec<char>> {
-    let mut stacks = stacks.clone();
+fn execute_instructions_one(stacks: &[Vec<char>], instructions: Vec<Instruction>) -> Stacks {
+    let mut stacks = stacks.to_vec();
     for (move_amount, from_col, to_col) in instructions {
         let from_col = from_col as usize - 1;
         let to_col = to_col as usize - 1;
@@ -62,11 +62,8 @@ fn execute_instructions_one(
     stacks
 }
 
-fn execute_instructions_two(
-    stacks: &Vec<Vec<char>>,
-    instructions: Vec<(u32, u32, u32)>,
-) -> Vec<Vec<char>> {
-    let mut stacks = stacks.clone();
+fn execute_instructions_two(stacks: &[Vec<char>], instructions: Vec<Instruction>) -> Stacks {
+    let mut stacks = stacks.to_vec();
     for (move_amount, from_col, to_col) in instructions {
         let from_col = from_col as usize - 1;
         let to_col = to_col as usize - 1;
@@ -113,10 +110,17 @@ pub fn part_two(input: &str) -> Option<String> {
     Some(result)
 }
 
+advent_of_code::solution!(2022, 5);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let args = advent_of_code::DayArgs::from_env();
+    let input = &args.read_input(2022, 5);
+    if args.runs_part(1) {
+        advent_of_code::solve!(2022, 5, 1, part_one, input);
+    }
+    if args.runs_part(2) {
+        advent_of_code::solve!(2022, 5, 2, part_two, input);
+    }
 }
 
 #[cfg(test)]
@@ -125,13 +129,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5);
+        let input = advent_of_code::read_file("examples", 2022, 5);
         assert_eq!(part_one(&input), Some("CMZ".to_string()));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5);
+        let input = advent_of_code::read_file("examples", 2022, 5);
         assert_eq!(part_two(&input), Some("MCD".to_string()));
     }
 }
@@ -2,7 +2,7 @@ fn parse_input(input: &str) -> Vec<char> {
     input.chars().filter(|c| c.is_alphabetic()).collect()
 }
 
-fn locate_unique_sequence(input: &Vec<char>, sequence_length: &usize) -> Option<u32> {
+fn locate_unique_sequence(input: &[char], sequence_length: &usize) -> Option<u32> {
     let length = input.len();
     for i in (sequence_length - 1)..length {
         let chunk = input[i - (sequence_length - 1)..i + 1].to_vec();
@@ -19,35 +19,49 @@ fn locate_unique_sequence(input: &Vec<char>, sequence_length: &usize) -> Option<
 
 pub fn part_one(input: &str) -> Option<u32> {
     let parsed_input = parse_input(input);
-    let sequence_length = 4 as usize;
+    let sequence_length = 4_usize;
     locate_unique_sequence(&parsed_input, &sequence_length)
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
     let parsed_input = parse_input(input);
-    let sequence_length = 14 as usize;
+    let sequence_length = 14_usize;
     locate_unique_sequence(&parsed_input, &sequence_length)
 }
 
+advent_of_code::solution!(2022, 6);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 6);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let args = advent_of_code::DayArgs::from_env();
+    let input = &args.read_input(2022, 6);
+    if args.runs_part(1) {
+        advent_of_code::solve!(2022, 6, 1, part_one, input);
+    }
+    if args.runs_part(2) {
+        advent_of_code::solve!(2022, 6, 2, part_two, input);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 6);
-        assert_eq!(part_one(&input), Some(7));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 6);
-        assert_eq!(part_two(&input), Some(19));
+    advent_of_code::example_tests! {
+        year: 2022,
+        day: 6,
+        part_one: {
+            a: Some(7),
+            b: Some(5),
+            c: Some(6),
+            d: Some(10),
+            e: Some(11),
+        },
+        part_two: {
+            a: Some(19),
+            b: Some(23),
+            c: Some(23),
+            d: Some(29),
+            e: Some(26),
+        },
     }
 }
@@ -75,8 +75,8 @@ fn parse_input(input: &str) -> FileStructure {
 
 pub fn part_one(input: &str) -> Option<u32> {
     let file_structure = parse_input(input);
-    let limit_size = 100000 as u32;
-    let mut total_size = 0 as u32;
+    let limit_size = 100000_u32;
+    let mut total_size = 0_u32;
     let mut dirs_to_check = Vec::new();
 
     dirs_to_check.push(&file_structure);
@@ -95,8 +95,8 @@ pub fn part_one(input: &str) -> Option<u32> {
 
 pub fn part_two(input: &str) -> Option<u32> {
     let file_structure = parse_input(input);
-    let disk_size = 70000000 as u32;
-    let update_size = 30000000 as u32;
+    let disk_size = 70000000_u32;
+    let update_size = 30000000_u32;
     let used_disk_size = file_structure.total_size();
     let free_disk_size = disk_size - used_disk_size;
     let space_to_free = update_size - free_disk_size;
@@ -123,10 +123,17 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(dir_size)
 }
 
+advent_of_code::solution!(2022, 7);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let args = advent_of_code::DayArgs::from_env();
+    let input = &args.read_input(2022, 7);
+    if args.runs_part(1) {
+        advent_of_code::solve!(2022, 7, 1, part_one, input);
+    }
+    if args.runs_part(2) {
+        advent_of_code::solve!(2022, 7, 2, part_two, input);
+    }
 }
 
 #[cfg(test)]
@@ -135,13 +142,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file("examples", 2022, 7);
         assert_eq!(part_one(&input), Some(95437));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file("examples", 2022, 7);
         assert_eq!(part_two(&input), Some(24933642));
     }
 }
@@ -0,0 +1,83 @@
+use advent_of_code::helpers::{Direction, GridError, Matrix2D};
+
+fn is_visible(tree_heights: &Matrix2D<u8>, position: (usize, usize), height: u8) -> bool {
+    // visible if every tree towards one of the edges is shorter
+    Direction::CARDINAL.iter().any(|&direction| {
+        tree_heights
+            .ray(position, direction)
+            .all(|(_, &other)| other < height)
+    })
+}
+
+fn get_view_distance(
+    tree_heights: &Matrix2D<u8>,
+    position: (usize, usize),
+    height: u8,
+    direction: Direction,
+) -> u32 {
+    // the view reaches up to and including the first tree at least as tall
+    let mut distance = 0;
+    for (_, &other) in tree_heights.ray(position, direction) {
+        distance += 1;
+        if other >= height {
+            break;
+        }
+    }
+    distance
+}
+
+fn get_scenic_score(tree_heights: &Matrix2D<u8>, position: (usize, usize), height: u8) -> u32 {
+    Direction::CARDINAL
+        .iter()
+        .map(|&direction| get_view_distance(tree_heights, position, height, direction))
+        .product()
+}
+
+pub fn part_one(input: &str) -> Result<u32, GridError> {
+    let tree_heights = Matrix2D::from_digits(input)?;
+    let total_visible = tree_heights
+        .cells()
+        .filter(|&(position, &height)| is_visible(&tree_heights, position, height))
+        .count();
+    Ok(total_visible as u32)
+}
+
+pub fn part_two(input: &str) -> Result<u32, GridError> {
+    let tree_heights = Matrix2D::from_digits(input)?;
+    let max_scenic_score = tree_heights
+        .cells()
+        .map(|(position, &height)| get_scenic_score(&tree_heights, position, height))
+        .max()
+        .unwrap_or(0);
+    Ok(max_scenic_score)
+}
+
+advent_of_code::solution!(2022, 8);
+
+fn main() {
+    let args = advent_of_code::DayArgs::from_env();
+    let input = &args.read_input(2022, 8);
+    if args.runs_part(1) {
+        advent_of_code::solve!(2022, 8, 1, part_one, input);
+    }
+    if args.runs_part(2) {
+        advent_of_code::solve!(2022, 8, 2, part_two, input);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 8);
+        assert_eq!(part_one(&input), Ok(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 8);
+        assert_eq!(part_two(&input), Ok(8));
+    }
+}
@@ -1,20 +1,23 @@
 use ::std::collections::HashSet;
 
-fn parse_input(input: &str) -> Vec<(u8, u8)> {
+fn parse_input(input: &str) -> Result<Vec<(u8, u8)>, String> {
     input
         .lines()
         .map(|line| {
-            let mut split = line.split(" ");
-            let direction_str = split.next().unwrap();
+            let (direction_str, steps_str) = line
+                .split_once(' ')
+                .ok_or_else(|| format!("invalid line: \"{}\"", line))?;
             let direction = match direction_str {
                 "R" => 0,
                 "U" => 1,
                 "L" => 2,
                 "D" => 3,
-                _ => panic!("Invalid direction: {}", direction_str),
+                _ => return Err(format!("invalid direction: \"{}\"", direction_str)),
             };
-            let steps = split.next().unwrap().parse::<u8>().unwrap();
-            (direction, steps)
+            let steps = steps_str
+                .parse::<u8>()
+                .map_err(|_| format!("invalid steps: \"{}\"", steps_str))?;
+            Ok((direction, steps))
         })
         .collect()
 }
@@ -39,9 +42,9 @@ fn move_knot(knot_position: (i32, i32), header_knot_position: (i32, i32)) -> (i3
         } else {
             0
         };
-        return (x + diff_x, y + diff_y);
+        (x + diff_x, y + diff_y)
     } else {
-        return (x, y);
+        (x, y)
     }
 }
 
@@ -56,60 +59,68 @@ fn move_rope(knot_positions: Vec<(i32, i32)>, movement: u8) -> Vec<(i32, i32)> {
     };
     let mut new_knot_positions = vec![(head_x_new, head_y_new)];
     for knot_position in knot_positions[1..].iter() {
-        let header_knot_position = new_knot_positions.last().unwrap().clone();
-        let new_knot_position = move_knot(knot_position.clone(), header_knot_position);
+        let header_knot_position = *new_knot_positions.last().unwrap();
+        let new_knot_position = move_knot(*knot_position, header_knot_position);
         new_knot_positions.push(new_knot_position);
     }
     new_knot_positions
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let parsed_input = parse_input(input);
+pub fn part_one(input: &str) -> Result<u32, String> {
+    let parsed_input = parse_input(input)?;
     let mut visited_positions = HashSet::new();
     let mut knot_positions = vec![(0, 0), (0, 0)];
 
     for (movement, steps) in parsed_input {
         for _ in 0..steps {
             knot_positions = move_rope(knot_positions, movement);
-            visited_positions.insert(knot_positions.last().unwrap().clone());
+            visited_positions.insert(*knot_positions.last().unwrap());
         }
     }
-    visited_positions.len().try_into().ok()
+    Ok(visited_positions.len() as u32)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let parsed_input = parse_input(input);
+pub fn part_two(input: &str) -> Result<u32, String> {
+    let parsed_input = parse_input(input)?;
     let mut visited_positions = HashSet::new();
     let mut knot_positions = vec![(0, 0); 10];
 
     for (movement, steps) in parsed_input {
         for _ in 0..steps {
             knot_positions = move_rope(knot_positions, movement);
-            visited_positions.insert(knot_positions.last().unwrap().clone());
+            visited_positions.insert(*knot_positions.last().unwrap());
         }
     }
-    visited_positions.len().try_into().ok()
+    Ok(visited_positions.len() as u32)
 }
 
+advent_of_code::solution!(2022, 9);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 9);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let args = advent_of_code::DayArgs::from_env();
+    let input = &args.read_input(2022, 9);
+    if args.runs_part(1) {
+        advent_of_code::solve!(2022, 9, 1, part_one, input);
+    }
+    if args.runs_part(2) {
+        advent_of_code::solve!(2022, 9, 2, part_two, input);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 9);
-        assert_eq!(part_one(&input), Some(13));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 9);
-        assert_eq!(part_two(&input), Some(1));
+    advent_of_code::example_tests! {
+        year: 2022,
+        day: 9,
+        part_one: {
+            a: Ok(13),
+            b: Ok(88),
+        },
+        part_two: {
+            a: Ok(1),
+            b: Ok(36),
+        },
     }
 }
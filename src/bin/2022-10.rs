@@ -1,3 +1,4 @@
+use advent_of_code::helpers::{GridError, Matrix2D};
 use std::collections::HashSet;
 
 fn parse_input(input: &str) -> Vec<(i32, i32)> {
@@ -5,11 +6,11 @@ fn parse_input(input: &str) -> Vec<(i32, i32)> {
         .lines()
         .map(|line| {
             if line == "noop" {
-                return (1, 0);
+                (1, 0)
             } else {
                 let split = line.split(" ");
                 let val = split.last().unwrap().parse::<i32>().unwrap();
-                return (2, val);
+                (2, val)
             }
         })
         .collect()
@@ -21,7 +22,7 @@ pub fn part_one(input: &str) -> Option<i32> {
     let mut interesting_cycles_signal_strengths = Vec::new();
     let mut state = (1, 1);
     for (cycles_to_completion, add_quantity) in instructions.iter() {
-        for i in 0..cycles_to_completion.clone() {
+        for i in 0..*cycles_to_completion {
             let cycle = state.0 + i;
             if interesting_cycles.contains(&cycle) {
                 let signal_strength = state.1 * cycle;
@@ -35,37 +36,39 @@ pub fn part_one(input: &str) -> Option<i32> {
     Some(interesting_cycles_signal_strengths.iter().sum())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Result<Matrix2D<bool>, GridError> {
     let instructions = parse_input(input);
     let mut register = 1;
     let mut cycle = 0;
     let mut pixels = Vec::new();
     for (cycles_to_completion, add_quantity) in instructions.iter() {
-        for _ in 0..cycles_to_completion.clone() {
+        for _ in 0..*cycles_to_completion {
             let column = cycle % 40;
             pixels.push(column >= register - 1 && column <= register + 1);
             cycle += 1;
         }
         register += add_quantity;
     }
-    // get pixels in chunks of 40
-    for row in pixels.chunks(40) {
-        for pixel in row {
-            if *pixel {
-                print!("#");
-            } else {
-                print!(".");
-            }
-        }
-        println!("");
+    if pixels.is_empty() {
+        return Err(GridError::Empty);
     }
-    None
+    // the screen is 40 pixels wide, printed as `#` (lit) and `.` (dark); a program that stops
+    // mid-row leaves a ragged screen
+    let screen = pixels.chunks(40).map(|row| row.to_vec()).collect();
+    Matrix2D::try_from_vec(screen)
 }
 
+advent_of_code::solution!(2022, 10);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let args = advent_of_code::DayArgs::from_env();
+    let input = &args.read_input(2022, 10);
+    if args.runs_part(1) {
+        advent_of_code::solve!(2022, 10, 1, part_one, input);
+    }
+    if args.runs_part(2) {
+        advent_of_code::solve!(2022, 10, 2, part_two, input);
+    }
 }
 
 #[cfg(test)]
@@ -74,13 +77,24 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = advent_of_code::read_file("examples", 2022, 10);
         assert_eq!(part_one(&input), Some(13140));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_two(&input), None);
+        let input = advent_of_code::read_file("examples", 2022, 10);
+        let screen = concat!(
+            "##..##..##..##..##..##..##..##..##..##..\n",
+            "###...###...###...###...###...###...###.\n",
+            "####....####....####....####....####....\n",
+            "#####.....#####.....#####.....#####.....\n",
+            "######......######......######......####\n",
+            "#######.......#######.......#######.....",
+        );
+        assert_eq!(
+            part_two(&input).map(|screen| screen.to_string()),
+            Ok(screen.to_string())
+        );
     }
 }
@@ -2,7 +2,7 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
+use advent_of_code::aoc_client::AocClient;
 use std::process;
 
 struct Args {
@@ -27,20 +27,19 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
-
-    match aoc_cli::download(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
-                process::exit(1);
-            }
-        }
+    let year = args.year.unwrap_or_else(|| {
+        advent_of_code::local_year_for_day(|year| advent_of_code::get_module_path(year, args.day))
+    });
+    let client = match AocClient::from_env(Some(year)) {
+        Ok(client) => client,
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
+            eprintln!("{}", e);
             process::exit(1);
         }
+    };
+
+    if let Err(e) = client.download(args.day) {
+        eprintln!("failed to download day {}: {}", args.day, e);
+        process::exit(1);
     }
 }
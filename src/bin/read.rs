@@ -2,8 +2,9 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
-use std::process;
+use advent_of_code::aoc_client::{self, AocClient};
+use advent_of_code::markdown;
+use std::{fs, process};
 
 struct Args {
     day: u8,
@@ -27,19 +28,33 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
-
-    match aoc_cli::read(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
+    let year = args.year.unwrap_or_else(|| {
+        advent_of_code::local_year_for_day(|year| aoc_client::get_puzzle_path(year, args.day))
+    });
+    let puzzle_path = aoc_client::get_puzzle_path(year, args.day);
+    if puzzle_path.exists() {
+        match fs::read_to_string(&puzzle_path) {
+            Ok(puzzle) => print!("{}", markdown::render(&puzzle)),
+            Err(e) => {
+                eprintln!("failed to read \"{}\": {}", puzzle_path.display(), e);
                 process::exit(1);
             }
         }
+        return;
+    }
+
+    let client = match AocClient::from_env(Some(year)) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    match client.get_puzzle(args.day) {
+        Ok(puzzle) => print!("{}", markdown::render(&puzzle)),
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
+            eprintln!("failed to read day {}: {}", args.day, e);
             process::exit(1);
         }
     }
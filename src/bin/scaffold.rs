@@ -2,113 +2,455 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::answers::{self, Answers};
+use advent_of_code::aoc_client::{self, AocClient};
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
+/// Placeholders: `{{YEAR}}`, `{{DAY}}`, `{{DAY_PADDED}}`, `{{TYPE}}` (the answer type) and
+/// `{{TITLE}}` (`Day N` unless the puzzle title is known). A `.template/day.rs` in the project
+/// root takes precedence over this one.
+const MODULE_TEMPLATE: &str = r###"//! {{TITLE}}
+// Solvers may also return `Result<T, E>` to report malformed input as an error.
+fn parse_input(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn part_one(input: &str) -> Option<{{TYPE}}> {
+    let _lines = parse_input(input);
     None
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Option<{{TYPE}}> {
+    let _lines = parse_input(input);
     None
 }
 
+advent_of_code::solution!({{YEAR}}, {{DAY}});
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let args = advent_of_code::DayArgs::from_env();
+    let input = &args.read_input({{YEAR}}, {{DAY}});
+    if args.runs_part(1) {
+        advent_of_code::solve!({{YEAR}}, {{DAY}}, 1, part_one, input);
+    }
+    if args.runs_part(2) {
+        advent_of_code::solve!({{YEAR}}, {{DAY}}, 2, part_two, input);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+    // add more examples as `src/{{YEAR}}/examples/{{DAY_PADDED}}-b.txt`, `-c.txt`, ...
+    advent_of_code::example_tests! {
+        year: {{YEAR}},
+        day: {{DAY}},
+        part_one: {
+            a: None,
+        },
+        part_two: {
+            a: None,
+        },
     }
+}
+"###;
+
+const ANSWER_TYPES: [&str; 5] = ["u32", "u64", "i32", "i64", "String"];
+
+struct Args {
+    day: u8,
+    year: Option<u16>,
+    answer_type: String,
+    download: bool,
+    force: bool,
+    dry_run: bool,
+}
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+fn parse_answer_type(answer_type: &str) -> Result<String, String> {
+    if ANSWER_TYPES.contains(&answer_type) {
+        Ok(answer_type.to_string())
+    } else {
+        Err(format!("expected one of {}", ANSWER_TYPES.join("|")))
     }
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        answer_type: args
+            .opt_value_from_fn(["-t", "--type"], parse_answer_type)?
+            .unwrap_or_else(|| "u32".to_string()),
+        download: args.contains(["-d", "--download"]),
+        force: args.contains(["-f", "--force"]),
+        dry_run: args.contains("--dry-run"),
+        day: args.free_from_str()?,
+    })
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// The user's `.template/day.rs` if there is one, otherwise the built-in template.
+fn load_template() -> Result<String, std::io::Error> {
+    let path = advent_of_code::project_root()
+        .join(".template")
+        .join("day.rs");
+    match fs::read_to_string(&path) {
+        Ok(template) => {
+            println!("Using module template \"{}\"", path.display());
+            Ok(template)
+        }
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(MODULE_TEMPLATE.to_string()),
+        Err(e) => Err(e),
+    }
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// What the scaffold writes to a target path.
+enum Contents {
+    /// An empty file, which never replaces an existing one, even with `--force`.
+    Placeholder,
+    Text(String),
+    /// An existing module with only its title line replaced, so `--download` never touches the
+    /// solution itself. `None` in a dry run, where the title is not downloaded.
+    Retitled(Option<String>),
+    /// Fetched from the puzzle page, which a dry run does not do.
+    Download,
 }
 
-fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
-            process::exit(1);
+struct Target {
+    name: &'static str,
+    path: PathBuf,
+    contents: Contents,
+}
+
+#[derive(PartialEq)]
+enum Action {
+    Create,
+    Overwrite,
+    /// Retitle an existing module, which needs no `--force`.
+    Update,
+    Keep,
+}
+
+impl Target {
+    fn new(name: &'static str, path: PathBuf, contents: Contents) -> Target {
+        Target {
+            name,
+            path,
+            contents,
         }
-    };
+    }
 
-    let day_padded = format!("{:02}", day);
+    fn action(&self) -> Action {
+        match (&self.contents, fs::read_to_string(&self.path)) {
+            (_, Err(e)) if e.kind() == ErrorKind::NotFound => Action::Create,
+            // empty files, e.g. left by a scaffold without `--download`, are filled in as if new
+            (Contents::Text(_) | Contents::Download, Ok(existing))
+                if existing.trim().is_empty() =>
+            {
+                Action::Create
+            }
+            (Contents::Text(new), Ok(existing)) if *new != existing => Action::Overwrite,
+            // unreadable files are left to fail when they are written
+            (Contents::Text(_), Err(_)) => Action::Overwrite,
+            (Contents::Retitled(Some(new)), Ok(existing)) if *new != existing => Action::Update,
+            (Contents::Retitled(None), _) => Action::Update,
+            // not known to be unchanged without downloading it
+            (Contents::Download, _) => Action::Overwrite,
+            _ => Action::Keep,
+        }
+    }
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    fn text(&self) -> &str {
+        match &self.contents {
+            Contents::Placeholder | Contents::Download | Contents::Retitled(None) => "",
+            Contents::Text(text) | Contents::Retitled(Some(text)) => text,
+        }
+    }
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+    fn describe(&self, action: &Action, dry_run: bool) -> String {
+        let (verb, past) = match (action, &self.contents) {
+            (Action::Keep, _) => ("keep", "Kept"),
+            (_, Contents::Download) => ("download", "Downloaded"),
+            (Action::Create, _) => ("create", "Created"),
+            (Action::Overwrite, _) => ("overwrite", "Overwrote"),
+            (Action::Update, _) => ("update the title of", "Updated the title of"),
+        };
+        let what = match (action, &self.contents) {
+            (Action::Keep, _) => format!("existing {} file", self.name),
+            (_, Contents::Placeholder) => format!("empty {} file", self.name),
+            (_, _) => format!("{} file", self.name),
+        };
+        if dry_run {
+            format!("Would {} {}", verb, what)
+        } else {
+            format!("{} {}", past, what)
+        }
+    }
+}
+
+/// Creates the missing ancestors of `path`, recording them outermost first in `created`.
+fn create_parents(path: &Path, created: &mut Vec<PathBuf>) -> io::Result<()> {
+    let missing: Vec<&Path> = path
+        .ancestors()
+        .skip(1)
+        .take_while(|dir| !dir.as_os_str().is_empty() && !dir.exists())
+        .collect();
+    for dir in missing.into_iter().rev() {
+        fs::create_dir(dir)?;
+        created.push(dir.to_path_buf());
+    }
+    Ok(())
+}
+
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(suffix);
+    path.with_file_name(file_name)
+}
+
+/// Writes every target or none: contents go to temporary files first, which are only renamed
+/// into place once all of them were written, and files being replaced are moved aside to a backup
+/// until then. On failure everything created is removed and the backups are restored.
+fn write_all(targets: &[(&Target, Action)]) -> io::Result<()> {
+    let mut created_dirs = Vec::new();
+    let mut temp_files = Vec::new();
+    let mut backups = Vec::new();
+    let mut placed = Vec::new();
+
+    let result = (|| {
+        for (target, _) in targets {
+            create_parents(&target.path, &mut created_dirs)?;
+            let temp = sibling_path(&target.path, ".scaffold-tmp");
+            temp_files.push(temp.clone());
+            fs::write(&temp, target.text())?;
+        }
+        for (target, _) in targets {
+            if target.path.exists() {
+                let backup = sibling_path(&target.path, ".scaffold-bak");
+                fs::rename(&target.path, &backup)?;
+                backups.push((target.path.clone(), backup));
+            }
+        }
+        for (target, _) in targets {
+            fs::rename(sibling_path(&target.path, ".scaffold-tmp"), &target.path)?;
+            placed.push(target.path.clone());
+        }
+        Ok(())
+    })();
+
+    match result {
+        Ok(()) => {
+            for (_, backup) in backups.iter() {
+                let _ = fs::remove_file(backup);
+            }
+        }
+        Err(_) => {
+            for file in temp_files.iter().chain(placed.iter()) {
+                let _ = fs::remove_file(file);
+            }
+            for (path, backup) in backups.iter() {
+                let _ = fs::rename(backup, path);
+            }
+            for dir in created_dirs.iter().rev() {
+                let _ = fs::remove_dir(dir);
+            }
+        }
+    }
+    result
+}
+
+/// Replaces the `//! ` title line a module starts with, keeping the rest of the solution.
+fn retitle(module: &str, title: &str) -> String {
+    match module.split_once('\n') {
+        Some((first, rest)) if first.starts_with("//! ") => format!("//! {}\n{}", title, rest),
+        _ => module.to_string(),
+    }
+}
+
+/// Fetches the input and puzzle for `day` without writing anything.
+fn download(year: u16, day: u8) -> (String, String) {
+    let client = match AocClient::from_env(Some(year)) {
+        Ok(client) => client,
         Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
+            eprintln!("{}", e);
             process::exit(1);
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
+    match client
+        .get_input(day)
+        .and_then(|input| Ok((input, client.get_puzzle(day)?)))
+    {
+        Ok(downloaded) => downloaded,
         Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
+            eprintln!("failed to download day {}: {}", day, e);
             process::exit(1);
         }
     }
+}
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(pico_args::Error::MissingArgument) => {
+            eprintln!(
+                "Need to specify a day (as integer). example: `cargo scaffold 7 --year 2022`"
+            );
+            process::exit(1);
         }
         Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
+            eprintln!("Failed to process arguments: {}", e);
             process::exit(1);
         }
-    }
+    };
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
+    let day = args.day;
+    let year = args.year.unwrap_or_else(|| {
+        advent_of_code::local_year_for_day(|year| advent_of_code::get_module_path(year, day))
+    });
+    let day_padded = format!("{:02}", day);
+    let bin_name = format!("{}-{}", year, day_padded);
+
+    let answers_path = answers::answers_path(year, day);
+    let template = match load_template() {
+        Ok(template) => template,
         Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
+            eprintln!("Failed to read module template: {}", e);
             process::exit(1);
         }
+    };
+
+    // a dry run only lists what would be downloaded
+    let (input, puzzle) = if args.download && !args.dry_run {
+        let (input, puzzle) = download(year, day);
+        (Some(input), Some(puzzle))
+    } else {
+        (None, None)
+    };
+    let title = puzzle
+        .as_deref()
+        .and_then(aoc_client::puzzle_title)
+        .unwrap_or_else(|| format!("Day {}", day));
+    let answers = puzzle
+        .as_deref()
+        .map(Answers::from_puzzle)
+        .filter(|found| *found != Answers::default())
+        .map(|found| {
+            let mut answers = Answers::load(&answers_path).unwrap_or_default();
+            answers.merge(found);
+            answers.to_string()
+        });
+
+    let module_path = advent_of_code::get_module_path(year, day);
+    let existing_module = fs::read_to_string(&module_path)
+        .ok()
+        .filter(|module| !module.trim().is_empty());
+    let module = match existing_module {
+        // downloading keeps an existing solution and only fills in its title
+        Some(existing) if args.download => {
+            Contents::Retitled(puzzle.is_some().then(|| retitle(&existing, &title)))
+        }
+        _ => Contents::Text(
+            template
+                .replace("{{YEAR}}", &year.to_string())
+                .replace("{{DAY_PADDED}}", &day_padded)
+                .replace("{{DAY}}", &day.to_string())
+                .replace("{{TITLE}}", &title)
+                .replace("{{TYPE}}", &args.answer_type),
+        ),
+    };
+    // everything taken from the puzzle page is unknown in a dry run, which downloads nothing
+    let fetched = |text: Option<String>| match text {
+        _ if args.download && args.dry_run => Contents::Download,
+        Some(text) => Contents::Text(text),
+        None => Contents::Placeholder,
+    };
+
+    let mut targets = vec![
+        Target::new("module", module_path, module),
+        Target::new(
+            "input",
+            advent_of_code::get_file_path("inputs", year, day),
+            fetched(input),
+        ),
+        Target::new(
+            "example",
+            advent_of_code::get_year_dir("examples", year).join(format!("{}-a.txt", day_padded)),
+            fetched(puzzle.as_deref().and_then(aoc_client::first_example)),
+        ),
+        Target::new("answers", answers_path, fetched(answers)),
+    ];
+    if args.download {
+        targets.push(Target::new(
+            "puzzle",
+            aoc_client::get_puzzle_path(year, day),
+            fetched(puzzle),
+        ));
+    }
+    let planned: Vec<(&Target, Action)> = targets
+        .iter()
+        .map(|target| (target, target.action()))
+        .collect();
+
+    let conflicts: Vec<&Target> = planned
+        .iter()
+        .filter(|(_, action)| *action == Action::Overwrite)
+        .map(|(target, _)| *target)
+        .collect();
+    if !conflicts.is_empty() && !args.force && !args.dry_run {
+        eprintln!("Refusing to overwrite existing files:");
+        for target in conflicts {
+            eprintln!("  {} \"{}\"", target.name, target.path.display());
+        }
+        eprintln!("Nothing was written. Pass `--force` to overwrite them.");
+        process::exit(1);
+    }
+
+    if args.dry_run {
+        if args.download {
+            println!(
+                "Dry run, nothing is downloaded: the title, example and answers are not filled in."
+            );
+        }
+        for (target, action) in planned.iter() {
+            let note = match (action, &target.contents) {
+                (Action::Overwrite, Contents::Download) if !args.force => {
+                    " (needs `--force` if it changed)"
+                }
+                (Action::Overwrite, _) if !args.force => " (needs `--force`)",
+                (Action::Update, Contents::Retitled(None)) => " (if it changed)",
+                _ => "",
+            };
+            println!(
+                "{} \"{}\"{}",
+                target.describe(action, true),
+                target.path.display(),
+                note
+            );
+        }
+        return;
+    }
+
+    let (keep, write): (Vec<_>, Vec<_>) = planned
+        .into_iter()
+        .partition(|(_, action)| *action == Action::Keep);
+    if let Err(e) = write_all(&write) {
+        eprintln!("Failed to scaffold day {}, nothing was written: {}", day, e);
+        process::exit(1);
+    }
+    for (target, action) in write.iter().chain(keep.iter()) {
+        println!(
+            "{} \"{}\"",
+            target.describe(action, false),
+            target.path.display()
+        );
     }
 
     println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    println!("🎄 Type `cargo solve {}` to run your solution.", &bin_name);
 }
@@ -2,86 +2,725 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+use crate::ANSI_RESET;
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
 
-pub struct Matrix2D<T: std::clone::Clone> {
+/// 256-colour foreground escapes from cold (blue) to hot (red), see [`heat`].
+const HEAT_COLORS: [&str; 12] = [
+    "\x1b[38;5;21m",
+    "\x1b[38;5;27m",
+    "\x1b[38;5;33m",
+    "\x1b[38;5;39m",
+    "\x1b[38;5;45m",
+    "\x1b[38;5;49m",
+    "\x1b[38;5;46m",
+    "\x1b[38;5;118m",
+    "\x1b[38;5;226m",
+    "\x1b[38;5;214m",
+    "\x1b[38;5;208m",
+    "\x1b[38;5;196m",
+];
+
+/// The ANSI colour for `fraction` (0 to 1) of the way from cold to hot, for heatmaps.
+pub fn heat(fraction: f64) -> &'static str {
+    let index = (fraction.clamp(0_f64, 1_f64) * (HEAT_COLORS.len() - 1) as f64).round();
+    HEAT_COLORS[index as usize]
+}
+
+/// A step from a cell to one of its eight neighbours, with north pointing to row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// The change in `(row, col)` for one step.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::NorthEast => (-1, 1),
+            Direction::East => (0, 1),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (1, 0),
+            Direction::SouthWest => (1, -1),
+            Direction::West => (0, -1),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+    /// A row whose length differs from the first row's. Lines are numbered from 1.
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A cell the parser rejected. Lines and columns are numbered from 1.
+    InvalidCell {
+        line: usize,
+        column: usize,
+        cell: char,
+        reason: String,
+    },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "grid is empty"),
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {} has {} cells, expected {} like the first line",
+                line, found, expected
+            ),
+            GridError::InvalidCell {
+                line,
+                column,
+                cell,
+                reason,
+            } => write!(
+                f,
+                "line {}, column {}: invalid cell {:?}: {}",
+                line, column, cell, reason
+            ),
+        }
+    }
+}
+
+/// A grid stored row by row in a single `Vec`, indexed by `(row, col)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix2D<T> {
     pub rows: usize,
     pub cols: usize,
-    data: Vec<Vec<T>>,
+    data: Vec<T>,
 }
 
-impl<T: std::clone::Clone> Matrix2D<T> {
+impl<T: Clone> Matrix2D<T> {
     pub fn new(rows: usize, cols: usize, default: T) -> Matrix2D<T> {
-        let data = vec![vec![default; cols]; rows];
+        let data = vec![default; rows * cols];
         Matrix2D { rows, cols, data }
     }
 
+    /// Rotated a quarter turn clockwise `turns` times; negative turns rotate counterclockwise.
+    pub fn rotate(&self, turns: i32) -> Matrix2D<T> {
+        self.view().rotate(turns).to_matrix()
+    }
+
+    pub fn rotate_cw(&self) -> Matrix2D<T> {
+        self.view().rotate_cw().to_matrix()
+    }
+
+    pub fn rotate_ccw(&self) -> Matrix2D<T> {
+        self.view().rotate_ccw().to_matrix()
+    }
+
+    pub fn transpose(&self) -> Matrix2D<T> {
+        self.view().transpose().to_matrix()
+    }
+
+    /// Mirrored left to right.
+    pub fn flip_horizontal(&self) -> Matrix2D<T> {
+        self.view().flip_horizontal().to_matrix()
+    }
+
+    /// Mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Matrix2D<T> {
+        self.view().flip_vertical().to_matrix()
+    }
+}
+
+impl Matrix2D<char> {
+    /// Parses a grid of characters, one row per line.
+    pub fn from_str_chars(input: &str) -> Result<Matrix2D<char>, GridError> {
+        Matrix2D::parse_grid(input, Ok::<_, &str>)
+    }
+}
+
+impl Matrix2D<u8> {
+    /// Parses a grid of single digits such as `30373`, one row per line.
+    pub fn from_digits(input: &str) -> Result<Matrix2D<u8>, GridError> {
+        Matrix2D::parse_grid(input, |c| {
+            c.to_digit(10).map(|digit| digit as u8).ok_or("not a digit")
+        })
+    }
+}
+
+impl<T> Matrix2D<T> {
+    /// Panics if the rows differ in length; an empty `data` gives an empty matrix.
     pub fn from_vec(data: Vec<Vec<T>>) -> Matrix2D<T> {
+        Matrix2D::try_from_vec(data).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_from_vec(data: Vec<Vec<T>>) -> Result<Matrix2D<T>, GridError> {
         let rows = data.len();
-        let cols = data[0].len();
+        let cols = data.first().map_or(0, Vec::len);
 
-        // assert that all rows have the same length
-        for row in data.iter() {
-            assert_eq!(row.len(), cols);
+        if let Some((i, row)) = data.iter().enumerate().find(|(_, row)| row.len() != cols) {
+            return Err(GridError::Ragged {
+                line: i + 1,
+                expected: cols,
+                found: row.len(),
+            });
         }
 
-        Matrix2D { rows, cols, data }
+        Ok(Matrix2D {
+            rows,
+            cols,
+            data: data.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses one row per line of `input` with `parse` called on every character. Trailing
+    /// blank lines are ignored.
+    pub fn parse_grid<E: Display>(
+        input: &str,
+        mut parse: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Matrix2D<T>, GridError> {
+        let input = input.trim_end_matches(['\n', '\r']);
+        if input.is_empty() {
+            return Err(GridError::Empty);
+        }
+
+        let rows = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(j, cell)| {
+                        parse(cell).map_err(|e| GridError::InvalidCell {
+                            line: i + 1,
+                            column: j + 1,
+                            cell,
+                            reason: e.to_string(),
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<Vec<Vec<T>>, GridError>>()?;
+        Matrix2D::try_from_vec(rows)
     }
 
     pub fn shape(&self) -> (usize, usize) {
         (self.rows, self.cols)
     }
 
+    fn offset(&self, row: usize, col: usize) -> usize {
+        assert!(
+            row < self.rows && col < self.cols,
+            "index ({}, {}) out of bounds for a {}x{} matrix",
+            row,
+            col,
+            self.rows,
+            self.cols
+        );
+        row * self.cols + col
+    }
+
     pub fn get(&self, row: usize, col: usize) -> &T {
-        &self.data[row][col]
+        &self[(row, col)]
     }
 
-    pub fn get_row(&self, row: usize) -> Vec<&T> {
-        self.data[row].iter().collect()
+    /// Like `get`, but returns `None` outside the matrix instead of panicking.
+    pub fn get_checked(&self, row: usize, col: usize) -> Option<&T> {
+        (row < self.rows && col < self.cols).then(|| &self.data[row * self.cols + col])
     }
 
-    pub fn iter_rows(&self) -> impl Iterator<Item = Vec<&T>> {
-        self.data.iter().map(|row| row.iter().collect())
+    pub fn get_row(&self, row: usize) -> &[T] {
+        assert!(row < self.rows, "row {} out of bounds", row);
+        &self.data[row * self.cols..(row + 1) * self.cols]
     }
 
-    pub fn get_col(&self, col: usize) -> Vec<&T> {
-        self.data.iter().map(|row| &row[col]).collect()
+    pub fn get_row_mut(&mut self, row: usize) -> &mut [T] {
+        assert!(row < self.rows, "row {} out of bounds", row);
+        &mut self.data[row * self.cols..(row + 1) * self.cols]
     }
 
-    pub fn iter_cols(&self) -> impl Iterator<Item = Vec<&T>> {
+    pub fn iter_rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        (0..self.rows).map(move |row| self.get_row(row))
+    }
+
+    pub fn get_col(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(col < self.cols, "column {} out of bounds", col);
+        (0..self.rows).map(move |row| &self.data[row * self.cols + col])
+    }
+
+    pub fn iter_cols(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator>
+           + ExactSizeIterator {
         (0..self.cols).map(move |col| self.get_col(col))
     }
 
+    /// Every cell with its `(row, col)`, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let cols = self.cols;
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i / cols, i % cols), cell))
+    }
+
+    /// The position one step from `(row, col)` in `direction`, if it is inside the matrix.
+    pub fn step(&self, (row, col): (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+        let (row_offset, col_offset) = direction.offset();
+        let row = row.checked_add_signed(row_offset)?;
+        let col = col.checked_add_signed(col_offset)?;
+        (row < self.rows && col < self.cols).then_some((row, col))
+    }
+
+    /// The up to four cells sharing an edge with `position`.
+    pub fn neighbours4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.neighbours(position, &Direction::CARDINAL)
+    }
+
+    /// The up to eight cells sharing an edge or a corner with `position`.
+    pub fn neighbours8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.neighbours(position, &Direction::ALL)
+    }
+
+    fn neighbours(
+        &self,
+        position: (usize, usize),
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        directions.iter().filter_map(move |&direction| {
+            let neighbour = self.step(position, direction)?;
+            Some((neighbour, &self[neighbour]))
+        })
+    }
+
+    /// The cells from `from` (exclusive) to the edge of the matrix in `direction`, nearest first.
+    pub fn ray(
+        &self,
+        from: (usize, usize),
+        direction: Direction,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        std::iter::successors(self.step(from, direction), move |&position| {
+            self.step(position, direction)
+        })
+        .map(move |position| (position, &self[position]))
+    }
+
+    /// Draws the matrix with one character per cell, as returned by `symbol`.
+    pub fn renderer<'a>(&'a self, symbol: impl Fn(&T) -> char + 'a) -> Renderer<'a, T> {
+        Renderer {
+            matrix: self,
+            symbol: Box::new(symbol),
+            style: Box::new(|_| ""),
+            ansi: true,
+        }
+    }
+
+    /// A read-only view that can be rotated, flipped and transposed without copying the grid.
+    pub fn view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            matrix: self,
+            transposed: false,
+            flip_rows: false,
+            flip_cols: false,
+        }
+    }
+
     pub fn set(&mut self, row: usize, col: usize, value: T) {
-        self.data[row][col] = value;
+        self[(row, col)] = value;
     }
 
     pub fn set_row(&mut self, row: usize, values: Vec<T>) {
-        self.data[row] = values;
+        assert_eq!(values.len(), self.cols);
+        for (cell, value) in self.get_row_mut(row).iter_mut().zip(values) {
+            *cell = value;
+        }
     }
 
     pub fn set_col(&mut self, col: usize, values: Vec<T>) {
-        for (i, value) in values.iter().enumerate() {
-            self.data[i][col] = value.clone();
-        }
-    }
-    pub fn get_rotation(&self, turns: i32) -> Matrix2D<T> {
-        let actual_turns = turns % 4;
-        if actual_turns == 0 {
-            return Matrix2D {
-                rows: self.cols.clone(),
-                cols: self.rows.clone(),
-                data: self.data.clone(),
-            };
-        } else if actual_turns == 1 {
-            let mut rotated = Matrix2D::new(self.cols, self.rows, self.data[0][0].clone());
-            for i in 0..rotated.rows {
-                for j in 0..rotated.cols {
-                    rotated.set(i, j, self.get(self.rows - j - 1, i).clone());
+        assert_eq!(values.len(), self.rows);
+        for (row, value) in values.into_iter().enumerate() {
+            self[(row, col)] = value;
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix2D<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self.data[self.offset(row, col)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix2D<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        let offset = self.offset(row, col);
+        &mut self.data[offset]
+    }
+}
+
+/// Prints rows as lines of characters.
+impl Display for Matrix2D<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.renderer(|&cell| cell).fmt(f)
+    }
+}
+
+/// Prints `true` as `#` and `false` as `.`, like the puzzles draw their grids.
+impl Display for Matrix2D<bool> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.renderer(|&cell| if cell { '#' } else { '.' }).fmt(f)
+    }
+}
+
+/// Draws a [`Matrix2D`] with one character per cell, created with [`Matrix2D::renderer`].
+/// Rows are separated by newlines, without one after the last row.
+pub struct Renderer<'a, T> {
+    matrix: &'a Matrix2D<T>,
+    symbol: Box<dyn Fn(&T) -> char + 'a>,
+    style: Box<dyn Fn(&T) -> &'static str + 'a>,
+    ansi: bool,
+}
+
+impl<'a, T> Renderer<'a, T> {
+    /// Styles each cell with the ANSI escape returned by `style`, such as
+    /// [`ANSI_BOLD`](crate::ANSI_BOLD) or a [`heat`] colour. An empty string leaves the cell
+    /// unstyled.
+    pub fn style(mut self, style: impl Fn(&T) -> &'static str + 'a) -> Renderer<'a, T> {
+        self.style = Box::new(style);
+        self
+    }
+
+    /// Whether to emit the ANSI styles at all, e.g. off when the output is not a terminal.
+    pub fn ansi(mut self, enabled: bool) -> Renderer<'a, T> {
+        self.ansi = enabled;
+        self
+    }
+}
+
+impl<T> Display for Renderer<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.matrix.iter_rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                let symbol = (self.symbol)(cell);
+                let style = if self.ansi { (self.style)(cell) } else { "" };
+                if style.is_empty() {
+                    write!(f, "{}", symbol)?;
+                } else {
+                    write!(f, "{}{}{}", style, symbol, ANSI_RESET)?;
                 }
             }
-            return rotated;
+        }
+        Ok(())
+    }
+}
+
+/// A rotated, flipped or transposed [`Matrix2D`], created with [`Matrix2D::view`]. Lookups are
+/// mapped back onto the underlying matrix.
+#[derive(Debug)]
+pub struct MatrixView<'a, T> {
+    matrix: &'a Matrix2D<T>,
+    // view coordinates have their rows and columns flipped first, then are transposed
+    transposed: bool,
+    flip_rows: bool,
+    flip_cols: bool,
+}
+
+impl<T> Clone for MatrixView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatrixView<'_, T> {}
+
+impl<'a, T> MatrixView<'a, T> {
+    pub fn shape(&self) -> (usize, usize) {
+        if self.transposed {
+            (self.matrix.cols, self.matrix.rows)
+        } else {
+            (self.matrix.rows, self.matrix.cols)
+        }
+    }
+
+    pub fn get_checked(&self, row: usize, col: usize) -> Option<&'a T> {
+        let (rows, cols) = self.shape();
+        if row >= rows || col >= cols {
+            return None;
+        }
+        let row = if self.flip_rows { rows - 1 - row } else { row };
+        let col = if self.flip_cols { cols - 1 - col } else { col };
+        if self.transposed {
+            self.matrix.get_checked(col, row)
         } else {
-            return self.get_rotation(actual_turns - 1).get_rotation(1);
+            self.matrix.get_checked(row, col)
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> &'a T {
+        let (rows, cols) = self.shape();
+        self.get_checked(row, col).unwrap_or_else(|| {
+            panic!(
+                "index ({}, {}) out of bounds for a {}x{} view",
+                row, col, rows, cols
+            )
+        })
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T>> {
+        let view = *self;
+        let (rows, cols) = self.shape();
+        (0..rows).map(move |row| (0..cols).map(move |col| view.get(row, col)))
+    }
+
+    pub fn transpose(self) -> MatrixView<'a, T> {
+        MatrixView {
+            transposed: !self.transposed,
+            flip_rows: self.flip_cols,
+            flip_cols: self.flip_rows,
+            ..self
+        }
+    }
+
+    pub fn flip_horizontal(self) -> MatrixView<'a, T> {
+        MatrixView {
+            flip_cols: !self.flip_cols,
+            ..self
         }
     }
+
+    pub fn flip_vertical(self) -> MatrixView<'a, T> {
+        MatrixView {
+            flip_rows: !self.flip_rows,
+            ..self
+        }
+    }
+
+    pub fn rotate_cw(self) -> MatrixView<'a, T> {
+        self.transpose().flip_horizontal()
+    }
+
+    pub fn rotate_ccw(self) -> MatrixView<'a, T> {
+        self.transpose().flip_vertical()
+    }
+
+    pub fn rotate(self, turns: i32) -> MatrixView<'a, T> {
+        (0..turns.rem_euclid(4)).fold(self, |view, _| view.rotate_cw())
+    }
+
+    pub fn to_matrix(&self) -> Matrix2D<T>
+    where
+        T: Clone,
+    {
+        let (rows, cols) = self.shape();
+        Matrix2D {
+            rows,
+            cols,
+            data: self.iter_rows().flatten().cloned().collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_indexing() {
+        let mut matrix = Matrix2D::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(matrix.shape(), (2, 3));
+        assert_eq!(matrix[(1, 0)], 4);
+        assert_eq!(matrix.get_checked(1, 2), Some(&6));
+        assert_eq!(matrix.get_checked(0, 3), None);
+        assert_eq!(matrix.get_checked(2, 0), None);
+
+        matrix[(0, 1)] = 7;
+        matrix.set_col(2, vec![8, 9]);
+        assert_eq!(matrix.get_row(0), &[1, 7, 8]);
+        assert_eq!(matrix.get_col(2).copied().collect::<Vec<_>>(), vec![8, 9]);
+        assert_eq!(
+            matrix
+                .iter_cols()
+                .map(|col| col.sum())
+                .collect::<Vec<i32>>(),
+            vec![5, 12, 17]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_index_out_of_bounds() {
+        let matrix = Matrix2D::new(2, 2, 0);
+        let _ = matrix[(0, 2)];
+    }
+
+    #[test]
+    fn test_parse_grid() {
+        let digits = Matrix2D::from_digits("303\n255\n").unwrap();
+        assert_eq!(
+            digits,
+            Matrix2D::from_vec(vec![vec![3, 0, 3], vec![2, 5, 5]])
+        );
+        assert_eq!(Matrix2D::from_str_chars("#.\r\n.#").unwrap()[(1, 0)], '.');
+
+        assert_eq!(
+            Matrix2D::from_digits("303\n2x5\n"),
+            Err(GridError::InvalidCell {
+                line: 2,
+                column: 2,
+                cell: 'x',
+                reason: "not a digit".to_string()
+            })
+        );
+        assert_eq!(
+            Matrix2D::from_str_chars("ab\nabc\n"),
+            Err(GridError::Ragged {
+                line: 2,
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(Matrix2D::from_str_chars("\n"), Err(GridError::Empty));
+        assert_eq!(Matrix2D::<u8>::from_vec(vec![]).shape(), (0, 0));
+    }
+
+    #[test]
+    fn test_neighbours_and_rays() {
+        let matrix = Matrix2D::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        let values = |cells: Vec<((usize, usize), &i32)>| -> Vec<i32> {
+            cells.into_iter().map(|(_, &value)| value).collect()
+        };
+
+        assert_eq!(values(matrix.neighbours4((0, 0)).collect()), vec![2, 4]);
+        assert_eq!(
+            values(matrix.neighbours4((1, 1)).collect()),
+            vec![2, 6, 8, 4]
+        );
+        assert_eq!(values(matrix.neighbours8((0, 2)).collect()), vec![6, 5, 2]);
+        assert_eq!(matrix.neighbours8((1, 1)).count(), 8);
+
+        assert_eq!(
+            values(matrix.ray((2, 1), Direction::North).collect()),
+            vec![5, 2]
+        );
+        assert_eq!(
+            matrix.ray((2, 0), Direction::NorthEast).collect::<Vec<_>>(),
+            vec![((1, 1), &5), ((0, 2), &3)]
+        );
+        assert_eq!(matrix.ray((0, 0), Direction::West).count(), 0);
+        assert_eq!(matrix.step((0, 2), Direction::East), None);
+        assert_eq!(matrix.cells().nth(5), Some(((1, 2), &6)));
+    }
+
+    #[test]
+    fn test_display() {
+        let chars = Matrix2D::from_str_chars("ab\ncd\n").unwrap();
+        assert_eq!(chars.to_string(), "ab\ncd");
+        let pixels = Matrix2D::from_vec(vec![vec![true, false], vec![false, true]]);
+        assert_eq!(pixels.to_string(), "#.\n.#");
+
+        let heights = Matrix2D::from_digits("09").unwrap();
+        let renderer = heights
+            .renderer(|&height| char::from_digit(height as u32, 10).unwrap())
+            .style(|&height| if height > 5 { heat(1_f64) } else { "" });
+        assert_eq!(renderer.to_string(), "0\x1b[38;5;196m9\x1b[0m");
+        assert_eq!(renderer.ansi(false).to_string(), "09");
+        assert_eq!(heat(-1_f64), heat(0_f64));
+    }
+
+    #[test]
+    fn test_rotation() {
+        let matrix = Matrix2D::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let rotated = matrix.rotate_cw();
+        assert_eq!(
+            rotated,
+            Matrix2D::from_vec(vec![vec![4, 1], vec![5, 2], vec![6, 3]])
+        );
+        assert_eq!(
+            matrix.rotate_ccw(),
+            Matrix2D::from_vec(vec![vec![3, 6], vec![2, 5], vec![1, 4]])
+        );
+        assert_eq!(rotated.rotate(-1), matrix);
+        assert_eq!(matrix.rotate(2), matrix.rotate(-2));
+        assert_eq!(matrix.rotate(4), matrix);
+        assert_eq!(matrix.rotate(-7), rotated);
+    }
+
+    #[test]
+    fn test_flip_and_transpose() {
+        let matrix = Matrix2D::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(
+            matrix.transpose(),
+            Matrix2D::from_vec(vec![vec![1, 4], vec![2, 5], vec![3, 6]])
+        );
+        assert_eq!(
+            matrix.flip_horizontal(),
+            Matrix2D::from_vec(vec![vec![3, 2, 1], vec![6, 5, 4]])
+        );
+        assert_eq!(
+            matrix.flip_vertical(),
+            Matrix2D::from_vec(vec![vec![4, 5, 6], vec![1, 2, 3]])
+        );
+    }
+
+    #[test]
+    fn test_view() {
+        let matrix = Matrix2D::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let view = matrix.view().rotate_cw().flip_vertical().transpose();
+        assert_eq!(view.shape(), (2, 3));
+        assert_eq!(view[(0, 0)], 6);
+        assert_eq!(view.get_checked(1, 2), Some(&1));
+        assert_eq!(view.get_checked(2, 0), None);
+        assert_eq!(view.to_matrix(), matrix.rotate(2));
+        assert_eq!(matrix.view().rotate(3).to_matrix(), matrix.rotate_ccw());
+    }
 }
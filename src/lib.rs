@@ -3,232 +3,816 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
+use std::collections::BTreeMap;
 use std::env;
+use std::fmt::Display;
 use std::fs;
-
+use std::io::{self, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub mod answers;
+pub mod aoc_client;
+pub mod bench;
 pub mod helpers;
+pub mod markdown;
+pub mod report;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
+pub const ANSI_DIM: &str = "\x1b[2m";
+pub const ANSI_UNDERLINE: &str = "\x1b[4m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// A day's solvers, type-erased so they can be called in-process by the all-days runner.
+/// Implemented for each `src/bin/YYYY-NN.rs` by the [`solution!`] macro.
+pub trait Solution: Send + Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn part_one(&self, input: &str) -> Answer;
+    fn part_two(&self, input: &str) -> Answer;
+
+    fn solve_part(&self, part: u8, input: &str) -> Answer {
+        match part {
+            1 => self.part_one(input),
+            2 => self.part_two(input),
+            _ => panic!("invalid part: {}", part),
+        }
+    }
+}
+
+/// A solver's answer as text: `Ok(None)` if not solved yet, `Err` with a message if it failed.
+pub type Answer = Result<Option<String>, String>;
+
+/// What a solver may return: `Option<T>` (`None` while unsolved) or `Result<T, E>` to report
+/// malformed input instead of panicking.
+pub trait SolverOutput {
+    fn into_answer(self) -> Answer;
+}
+
+impl<T: Display> SolverOutput for Option<T> {
+    fn into_answer(self) -> Answer {
+        Ok(self.map(|answer| answer.to_string()))
+    }
+}
+
+impl<T: Display, E: Display> SolverOutput for Result<T, E> {
+    fn into_answer(self) -> Answer {
+        self.map(|answer| Some(answer.to_string()))
+            .map_err(|error| error.to_string())
+    }
+}
+
+/// All registered solutions, keyed by year and day.
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<(u16, u8), Box<dyn Solution>>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    pub fn register(&mut self, solution: impl Solution + 'static) {
+        self.solutions
+            .insert((solution.year(), solution.day()), Box::new(solution));
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&dyn Solution> {
+        self.solutions
+            .get(&(year, day))
+            .map(|solution| solution.as_ref())
+    }
+
+    /// All solutions in year and day order.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Solution> {
+        self.solutions.values().map(|solution| solution.as_ref())
+    }
+
+    /// The years with at least one solution, in ascending order.
+    pub fn years(&self) -> Vec<u16> {
+        let mut years: Vec<u16> = self.solutions.keys().map(|(year, _)| *year).collect();
+        years.dedup();
+        years
+    }
+
+    /// The year to use for `day` when none was given: the latest year with a solution for
+    /// that day, falling back to [`default_year`].
+    pub fn year_for_day(&self, day: u8) -> u16 {
+        self.solutions
+            .keys()
+            .filter(|(_, d)| *d == day)
+            .map(|(year, _)| *year)
+            .max()
+            .unwrap_or_else(default_year)
+    }
+}
+
+/// Implements [`Solution`] for the `part_one` / `part_two` functions of the calling module.
 #[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
+macro_rules! solution {
+    ($year:expr, $day:expr) => {
+        pub struct Solver;
+
+        impl advent_of_code::Solution for Solver {
+            fn year(&self) -> u16 {
+                $year
+            }
+
+            fn day(&self) -> u8 {
+                $day
+            }
+
+            fn part_one(&self, input: &str) -> advent_of_code::Answer {
+                advent_of_code::SolverOutput::into_answer(part_one(input))
+            }
+
+            fn part_two(&self, input: &str) -> advent_of_code::Answer {
+                advent_of_code::SolverOutput::into_answer(part_two(input))
             }
         }
+    };
+}
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+/// Outcome of running one part of one day's solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+    pub error: Option<Failure>,
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// Why a part has no answer even though it was attempted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// The solver returned an error, or the part could not be run (e.g. missing input).
+    Error(String),
+    /// The solver panicked, with the panic message.
+    Panicked(String),
+    /// The solver did not finish within the runner's timeout.
+    TimedOut(Duration),
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Error(error) => write!(f, "{}", error),
+            Failure::Panicked(message) => write!(f, "panicked: {}", message),
+            Failure::TimedOut(timeout) => write!(f, "timed out after {:.2?}", timeout),
+        }
+    }
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+    Error,
+    Panicked,
+    TimedOut,
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Solved => write!(f, "solved"),
+            Status::Unsolved => write!(f, "unsolved"),
+            Status::Error => write!(f, "error"),
+            Status::Panicked => write!(f, "panicked"),
+            Status::TimedOut => write!(f, "timed_out"),
+        }
+    }
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
+/// Name of the threads [`PartResult::time_isolated`] runs solvers on.
+const SOLVER_THREAD_NAME: &str = "aoc-solver";
+
+/// Stack size for isolated solvers, matching a typical main thread so recursive solvers
+/// behave the same as in their own binary.
+const SOLVER_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Keeps the default panic message of isolated solvers from interleaving with the runner's
+/// output; their panics are reported in the [`PartResult`] instead.
+pub fn silence_solver_panics() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if thread::current().name() != Some(SOLVER_THREAD_NAME) {
+            default_hook(info);
+        }
+    }));
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+impl PartResult {
+    /// Runs `solver` on `input` once, timing the call.
+    pub fn time(
+        year: u16,
+        day: u8,
+        part: u8,
+        solver: impl FnOnce(&str) -> Answer,
+        input: &str,
+    ) -> PartResult {
+        let timer = Instant::now();
+        let answer = solver(input);
+        let elapsed = timer.elapsed();
+
+        let (answer, error) = match answer {
+            Ok(answer) => (answer, None),
+            Err(error) => (None, Some(Failure::Error(error))),
+        };
+        PartResult {
+            year,
+            day,
+            part,
+            answer,
+            elapsed,
+            error,
+        }
+    }
+
+    /// Like [`PartResult::time`], but on a separate thread, so that a panicking solver or one
+    /// exceeding `timeout` is reported instead of aborting or hanging the caller. A timed-out
+    /// solver keeps running in the background until the process exits.
+    pub fn time_isolated(
+        year: u16,
+        day: u8,
+        part: u8,
+        solver: impl FnOnce(&str) -> Answer + Send + 'static,
+        input: Arc<str>,
+        timeout: Duration,
+    ) -> PartResult {
+        let (sender, receiver) = mpsc::channel();
+        let spawned = thread::Builder::new()
+            .name(SOLVER_THREAD_NAME.to_string())
+            .stack_size(SOLVER_STACK_SIZE)
+            .spawn(move || {
+                let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                    PartResult::time(year, day, part, solver, &input)
+                }));
+                let _ = sender.send(result);
+            });
+        if let Err(e) = spawned {
+            return PartResult::failed(year, day, part, format!("could not start solver: {}", e));
+        }
+
+        let failure = match receiver.recv_timeout(timeout) {
+            Ok(Ok(result)) => return result,
+            Ok(Err(payload)) => Failure::Panicked(panic_message(payload.as_ref())),
+            Err(mpsc::RecvTimeoutError::Timeout) => Failure::TimedOut(timeout),
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                Failure::Panicked("solver exited without a result".to_string())
             }
+        };
+        PartResult {
+            error: Some(failure),
+            ..PartResult::unsolved(year, day, part)
+        }
+    }
+
+    /// A part that was not run, e.g. because the day has no solution yet.
+    pub fn unsolved(year: u16, day: u8, part: u8) -> PartResult {
+        PartResult {
+            year,
+            day,
+            part,
+            answer: None,
+            elapsed: Duration::ZERO,
+            error: None,
         }
-    })
+    }
+
+    /// A part that could not be run or whose solver failed, e.g. because its input is missing.
+    pub fn failed(year: u16, day: u8, part: u8, error: impl Display) -> PartResult {
+        PartResult {
+            error: Some(Failure::Error(error.to_string())),
+            ..PartResult::unsolved(year, day, part)
+        }
+    }
+
+    pub fn status(&self) -> Status {
+        match (&self.answer, &self.error) {
+            (_, Some(Failure::Error(_))) => Status::Error,
+            (_, Some(Failure::Panicked(_))) => Status::Panicked,
+            (_, Some(Failure::TimedOut(_))) => Status::TimedOut,
+            (Some(_), None) => Status::Solved,
+            (None, None) => Status::Unsolved,
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.status() == Status::Solved
+    }
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
+pub fn print_result(result: &PartResult) {
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, result.part, ANSI_RESET);
+    match (&result.answer, &result.error) {
+        (_, Some(Failure::Error(error))) => {
+            println!("error: {}", error)
+        }
+        (_, Some(failure)) => {
+            println!("{}", failure)
+        }
+        // multi-line answers such as rendered screens start on their own line
+        (Some(answer), None) if answer.contains('\n') => {
+            println!("{}", answer);
+            println!(
+                "{}(elapsed: {:.2?}){}",
+                ANSI_ITALIC, result.elapsed, ANSI_RESET
+            );
+        }
+        (Some(answer), None) => {
+            println!(
+                "{} {}(elapsed: {:.2?}){}",
+                answer, ANSI_ITALIC, result.elapsed, ANSI_RESET
+            );
+        }
+        (None, None) => {
+            println!("not solved.")
+        }
+    }
+}
+
+/// Runs and prints one part of a day, evaluating to its [`PartResult`].
+/// The solver may return `Option<T>` or `Result<T, E>`, see [`SolverOutput`].
+#[macro_export]
+macro_rules! solve {
+    ($year:expr, $day:expr, $part:expr, $solver:ident, $input:expr) => {{
+        let result = advent_of_code::PartResult::time(
+            $year,
+            $day,
+            $part,
+            |input| advent_of_code::SolverOutput::into_answer($solver(input)),
+            $input,
         );
+        advent_of_code::print_result(&result);
+        result
     }};
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// The year used when none is given: `AOC_YEAR` if set, otherwise the current (or last) event.
+pub fn default_year() -> u16 {
+    env::var("AOC_YEAR")
+        .ok()
+        .and_then(|year| year.trim().parse().ok())
+        .unwrap_or_else(aoc_client::current_event_year)
+}
 
-    #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
+/// The year to use for a day when none was given: the latest year in `src` for which
+/// `day_file(year)` exists, e.g. the day's downloaded puzzle, otherwise the latest year in `src`,
+/// falling back to [`default_year`] in a repository without any.
+pub fn local_year_for_day(day_file: impl Fn(u16) -> PathBuf) -> u16 {
+    let mut years: Vec<u16> = fs::read_dir(project_root().join("src"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().to_str()?.parse().ok())
+        .collect();
+    years.sort_unstable();
+    years
+        .iter()
+        .rev()
+        .find(|year| day_file(**year).exists())
+        .or(years.last())
+        .copied()
+        .unwrap_or_else(default_year)
+}
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
+/// Root of this repository: `CARGO_MANIFEST_DIR` when run through cargo, otherwise the directory
+/// the crate was built in. Files are looked up relative to it, not the working directory.
+pub fn project_root() -> PathBuf {
+    env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")))
+}
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
+/// Directory holding a year's inputs, examples, puzzles and answers, e.g. `src/2022/inputs`.
+pub fn get_year_dir(folder: &str, year: u16) -> PathBuf {
+    project_root()
+        .join("src")
+        .join(year.to_string())
+        .join(folder)
+}
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
-    }
+pub fn get_file_path(folder: &str, year: u16, day: u8) -> PathBuf {
+    get_year_dir(folder, year).join(format!("{:02}.txt", day))
 }
 
-pub mod aoc_cli {
-    use std::{
-        fmt::Display,
-        fs::create_dir_all,
-        process::{Command, Output, Stdio},
-    };
+/// The day's solution module, e.g. `src/bin/2022-06.rs`.
+pub fn get_module_path(year: u16, day: u8) -> PathBuf {
+    project_root()
+        .join("src")
+        .join("bin")
+        .join(format!("{}-{:02}.rs", year, day))
+}
 
-    pub enum AocCliError {
-        CommandNotFound,
-        CommandNotCallable,
-        BadExitStatus(Output),
-        IoError,
+/// Reads a file kept by the template, e.g. answers or a baseline, or `None` if it does not exist
+/// yet.
+pub fn read_if_exists(path: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
     }
+}
 
-    impl Display for AocCliError {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            match self {
-                AocCliError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
-                AocCliError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
-                AocCliError::BadExitStatus(_) => {
-                    write!(f, "aoc-cli exited with a non-zero status.")
-                }
-                AocCliError::IoError => write!(f, "could not write output files to file system."),
+/// Writes a file kept by the template, creating its year directory on first use.
+pub fn write_creating_dirs(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// The project root could not be located, e.g. because the binary was moved.
+    ProjectRootNotFound(PathBuf),
+    NotFound {
+        path: PathBuf,
+        year: u16,
+        day: u8,
+    },
+    Empty {
+        path: PathBuf,
+        year: u16,
+        day: u8,
+    },
+    IoError(PathBuf, std::io::ErrorKind),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::ProjectRootNotFound(root) => write!(
+                f,
+                "could not find the project at \"{}\", run this through `cargo` from the repository.",
+                root.display()
+            ),
+            InputError::NotFound { path, year, day } => write!(
+                f,
+                "no input file \"{}\", try `cargo download {} --year {}`.",
+                path.display(),
+                day,
+                year
+            ),
+            InputError::Empty { path, year, day } => write!(
+                f,
+                "input file \"{}\" is empty, try `cargo download {} --year {}`.",
+                path.display(),
+                day,
+                year
+            ),
+            InputError::IoError(path, kind) => {
+                write!(f, "could not read \"{}\": {}.", path.display(), kind)
             }
         }
     }
+}
 
-    pub fn check() -> Result<(), AocCliError> {
-        Command::new("aoc")
-            .arg("-V")
-            .output()
-            .map_err(|_| AocCliError::CommandNotFound)?;
-        Ok(())
+/// Reads the real input for a day, explaining what to do if it is missing.
+pub fn try_read_input(year: u16, day: u8) -> Result<String, InputError> {
+    let root = project_root();
+    if !root.join("Cargo.toml").is_file() {
+        return Err(InputError::ProjectRootNotFound(root));
     }
+    read_input_file(get_file_path("inputs", year, day), year, day)
+}
 
-    pub fn read(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        // TODO: output local puzzle if present.
-        let args = build_args("read", &[], day, year);
-        call_aoc_cli(&args)
+fn read_input_file(path: PathBuf, year: u16, day: u8) -> Result<String, InputError> {
+    match fs::read_to_string(&path) {
+        Ok(input) if input.trim().is_empty() => Err(InputError::Empty { path, year, day }),
+        Ok(input) => Ok(input),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            Err(InputError::NotFound { path, year, day })
+        }
+        Err(e) => Err(InputError::IoError(path, e.kind())),
     }
+}
 
-    pub fn download(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        let input_path = get_input_path(day);
+/// Where a day binary reads its input from when not using `src/YYYY/inputs`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
 
-        let puzzle_path = get_puzzle_path(day);
-        create_dir_all("src/puzzles").map_err(|_| AocCliError::IoError)?;
+/// Arguments accepted by every day binary, e.g. `cargo solve 2022-06 -- --input big.txt --part 2`:
+/// `--input <path>` (or `-` for stdin) instead of the downloaded input, and `--part 1|2`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DayArgs {
+    pub input: Option<InputSource>,
+    pub part: Option<u8>,
+}
 
-        let args = build_args(
-            "download",
-            &[
-                "--overwrite".into(),
-                "--input-file".into(),
-                input_path.to_string(),
-                "--puzzle-file".into(),
-                puzzle_path.to_string(),
-            ],
-            day,
-            year,
-        );
+impl DayArgs {
+    pub fn parse(mut args: pico_args::Arguments) -> Result<DayArgs, pico_args::Error> {
+        let input = args.opt_value_from_os_str(["-i", "--input"], |path| {
+            Ok::<_, String>(if path == "-" {
+                InputSource::Stdin
+            } else {
+                InputSource::File(PathBuf::from(path))
+            })
+        })?;
+        let part = args.opt_value_from_fn(["-p", "--part"], parse_part)?;
+
+        let remaining = args.finish();
+        if !remaining.is_empty() {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: format!("unexpected arguments: {:?}", remaining),
+            });
+        }
+        Ok(DayArgs { input, part })
+    }
+
+    /// Parses the process arguments, exiting with a message if they are invalid.
+    pub fn from_env() -> DayArgs {
+        DayArgs::parse(pico_args::Arguments::from_env()).unwrap_or_else(|e| {
+            eprintln!("Failed to process arguments: {}", e);
+            std::process::exit(1);
+        })
+    }
 
-        let output = call_aoc_cli(&args)?;
+    pub fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|selected| selected == part)
+    }
 
-        if output.status.success() {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-            Ok(output)
-        } else {
-            Err(AocCliError::BadExitStatus(output))
+    pub fn try_read_input(&self, year: u16, day: u8) -> Result<String, InputError> {
+        match &self.input {
+            None => try_read_input(year, day),
+            Some(InputSource::File(path)) => {
+                fs::read_to_string(path).map_err(|e| InputError::IoError(path.clone(), e.kind()))
+            }
+            Some(InputSource::Stdin) => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| InputError::IoError(PathBuf::from("<stdin>"), e.kind()))?;
+                Ok(input)
+            }
         }
     }
 
-    fn get_input_path(day: u8) -> String {
-        let day_padded = format!("{:02}", day);
-        format!("src/inputs/{}.txt", day_padded)
+    /// Reads the selected input for a day's `main`, exiting with a helpful message if it is
+    /// unavailable.
+    pub fn read_input(&self, year: u16, day: u8) -> String {
+        self.try_read_input(year, day).unwrap_or_else(|e| {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        })
     }
+}
 
-    fn get_puzzle_path(day: u8) -> String {
-        let day_padded = format!("{:02}", day);
-        format!("src/puzzles/{}.md", day_padded)
+/// Parses a `--part` value.
+pub fn parse_part(part: &str) -> Result<u8, String> {
+    match part {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("expected 1 or 2, got \"{}\"", part)),
     }
+}
 
-    fn build_args(command: &str, args: &[String], day: u8, year: Option<u16>) -> Vec<String> {
-        let mut cmd_args = args.to_vec();
+/// Parses a day list such as `1-5,8` into sorted, deduplicated days.
+pub fn parse_days(list: &str) -> Result<Vec<u8>, String> {
+    let parse_day = |day: &str| match day.trim().parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("invalid day \"{}\", expected 1-25", day.trim())),
+    };
 
-        if let Some(year) = year {
-            cmd_args.push("--year".into());
-            cmd_args.push(year.to_string());
+    let mut days = Vec::new();
+    for item in list.split(',') {
+        match item.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse_day(start)?, parse_day(end)?);
+                if start > end {
+                    return Err(format!("invalid day range \"{}\"", item.trim()));
+                }
+                days.extend(start..=end);
+            }
+            None => days.push(parse_day(item)?),
         }
+    }
+    days.sort_unstable();
+    days.dedup();
+    Ok(days)
+}
+
+pub fn read_file(folder: &str, year: u16, day: u8) -> String {
+    let path = get_file_path(folder, year, day);
+    let f = fs::read_to_string(&path);
+    f.unwrap_or_else(|e| panic!("could not open \"{}\": {}", path.display(), e))
+}
+
+/// Reads the named example `src/YYYY/examples/NN-<name>.txt`, for days with more than one example.
+pub fn read_example(year: u16, day: u8, name: &str) -> String {
+    let path = get_year_dir("examples", year).join(format!("{:02}-{}.txt", day, name));
+    let f = fs::read_to_string(&path);
+    f.unwrap_or_else(|e| panic!("could not open \"{}\": {}", path.display(), e))
+}
 
-        cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+/// Generates one test per named example and part, each checking `part_one` / `part_two`
+/// against `src/YYYY/examples/NN-<name>.txt`:
+///
+/// ```ignore
+/// advent_of_code::example_tests! {
+///     year: 2022,
+///     day: 6,
+///     part_one: { a: Some(7), b: Some(5) },
+///     part_two: { a: Some(19) },
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    (year: $year:expr, day: $day:expr, $($part:ident: { $($example:ident: $expected:expr),* $(,)? }),* $(,)?) => {
+        $(
+            mod $part {
+                use super::*;
+
+                $(
+                    #[test]
+                    fn $example() {
+                        let input = advent_of_code::read_example($year, $day, stringify!($example));
+                        assert_eq!($part(&input), $expected);
+                    }
+                )*
+            }
+        )*
+    };
+}
 
-        cmd_args
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_result_time() {
+        let result = PartResult::time(2022, 1, 2, |input| Some(input.len()).into_answer(), "abc");
+        assert_eq!(result.year, 2022);
+        assert_eq!(result.day, 1);
+        assert_eq!(result.part, 2);
+        assert_eq!(result.answer, Some("3".to_string()));
+        assert!(result.is_solved());
+
+        let result = PartResult::time(2022, 1, 1, |_| None::<u32>.into_answer(), "abc");
+        assert_eq!(result.answer, None);
+        assert_eq!(result.status(), Status::Unsolved);
     }
 
-    fn call_aoc_cli(args: &[String]) -> Result<Output, AocCliError> {
-        if cfg!(debug_assertions) {
-            println!("Calling >aoc with: {}", args.join(" "));
-        }
+    #[test]
+    fn test_read_input_file() {
+        let dir = env::temp_dir().join(format!("aoc-read-input-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("06.txt");
+
+        let error = read_input_file(path.clone(), 2022, 6).unwrap_err();
+        assert!(matches!(error, InputError::NotFound { day: 6, .. }));
+        assert!(error
+            .to_string()
+            .ends_with("try `cargo download 6 --year 2022`."));
+
+        fs::write(&path, "\n").unwrap();
+        let error = read_input_file(path.clone(), 2022, 6).unwrap_err();
+        assert!(matches!(error, InputError::Empty { .. }));
+
+        fs::write(&path, "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n").unwrap();
+        assert_eq!(
+            read_input_file(path, 2022, 6).unwrap(),
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
 
-        Command::new("aoc")
-            .args(args)
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .output()
-            .map_err(|_| AocCliError::CommandNotCallable)
+    #[test]
+    fn test_read_write_creating_dirs() {
+        let dir = env::temp_dir().join(format!("aoc-write-{}", std::process::id()));
+        let path = dir.join("2022").join("answers").join("06.txt");
+
+        assert_eq!(read_if_exists(&path).unwrap(), None);
+        write_creating_dirs(&path, "7\n19\n").unwrap();
+        assert_eq!(read_if_exists(&path).unwrap(), Some("7\n19\n".to_string()));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_day_args() {
+        let parse = |args: &[&str]| {
+            DayArgs::parse(pico_args::Arguments::from_vec(
+                args.iter().map(|arg| arg.into()).collect(),
+            ))
+        };
+
+        assert_eq!(parse(&[]).unwrap(), DayArgs::default());
+        let args = parse(&["--input", "big.txt", "--part", "2"]).unwrap();
+        assert_eq!(
+            args.input,
+            Some(InputSource::File(PathBuf::from("big.txt")))
+        );
+        assert!(!args.runs_part(1));
+        assert!(args.runs_part(2));
+        assert_eq!(parse(&["-i", "-"]).unwrap().input, Some(InputSource::Stdin));
+
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["extra"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("1-5,8"), Ok(vec![1, 2, 3, 4, 5, 8]));
+        assert_eq!(parse_days("8, 3,2-3"), Ok(vec![2, 3, 8]));
+        assert!(parse_days("5-1").is_err());
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("1,,2").is_err());
+    }
+
+    #[test]
+    fn test_part_result_time_result() {
+        let result = PartResult::time(2022, 9, 1, |input| input.parse::<u32>().into_answer(), "13");
+        assert_eq!(result.answer, Some("13".to_string()));
+
+        let result = PartResult::time(
+            2022,
+            9,
+            1,
+            |input| input.parse::<u32>().into_answer(),
+            "X 4",
+        );
+        assert_eq!(result.answer, None);
+        assert_eq!(
+            result.error,
+            Some(Failure::Error("invalid digit found in string".to_string()))
+        );
+        assert_eq!(result.status(), Status::Error);
+    }
+
+    #[test]
+    fn test_part_result_time_isolated() {
+        let timeout = Duration::from_secs(5);
+        let input: Arc<str> = Arc::from("abc");
+
+        let result = PartResult::time_isolated(
+            2022,
+            1,
+            1,
+            |input| Some(input.len()).into_answer(),
+            input.clone(),
+            timeout,
+        );
+        assert_eq!(result.answer, Some("3".to_string()));
+
+        let result = PartResult::time_isolated(
+            2022,
+            1,
+            2,
+            |input| panic!("cannot parse {}", input),
+            input.clone(),
+            timeout,
+        );
+        assert_eq!(result.status(), Status::Panicked);
+        assert_eq!(
+            result.error.unwrap().to_string(),
+            "panicked: cannot parse abc"
+        );
+
+        let result = PartResult::time_isolated(
+            2022,
+            2,
+            1,
+            |_| {
+                thread::sleep(Duration::from_secs(1));
+                Ok(None)
+            },
+            input,
+            Duration::from_millis(10),
+        );
+        assert_eq!(result.status(), Status::TimedOut);
+        assert_eq!(result.error.unwrap().to_string(), "timed out after 10.00ms");
+    }
+
+    #[test]
+    fn test_part_result_failed() {
+        let result = PartResult::failed(2022, 3, 1, "no input file");
+        assert_eq!(
+            result.error,
+            Some(Failure::Error("no input file".to_string()))
+        );
+        assert_eq!(result.status(), Status::Error);
     }
 }
@@ -2,47 +2,653 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::answers::{self, Answers, Verdict, WrongGuesses};
+use advent_of_code::aoc_client::{AocClient, Submission};
+use advent_of_code::bench::{self, Baseline, BenchOptions};
+use advent_of_code::report::{self, Format};
+use advent_of_code::{PartResult, Registry, Status, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::collections::{btree_map::Entry, BTreeMap};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
+/// How long the all-days runner waits for a part before reporting it as timed out.
+const DEFAULT_TIMEOUT_SECS: f64 = 10_f64;
+
+struct AllArgs {
+    year: Option<u16>,
+    format: Format,
+    timeout: Duration,
+    /// Days run concurrently; results are still printed in day order.
+    jobs: usize,
+    days: Option<Vec<u8>>,
+    part: Option<u8>,
+    /// Only run parts with a recorded answer in `src/YYYY/answers`.
+    only_solved: bool,
+}
+
+struct BenchArgs {
+    year: Option<u16>,
+    day: Option<u8>,
+    runs: Option<usize>,
+    save_baseline: bool,
+    compare: bool,
+    threshold: f64,
+}
+
+enum Command {
+    All(AllArgs),
+    Bench(BenchArgs),
+    Verify {
+        year: Option<u16>,
+        day: Option<u8>,
+    },
+    Submit {
+        day: u8,
+        part: u8,
+        answer: Option<String>,
+        year: Option<u16>,
+    },
+}
+
+/// Parses a `--timeout` in seconds, which must be positive and finite.
+fn parse_timeout(secs: &str) -> Result<Duration, String> {
+    let secs = secs.parse::<f64>().map_err(|e| e.to_string())?;
+    match Duration::try_from_secs_f64(secs) {
+        Ok(timeout) if !timeout.is_zero() => Ok(timeout),
+        _ => Err(format!(
+            "expected a positive number of seconds, got {}",
+            secs
+        )),
+    }
+}
+
+fn parse_args() -> Result<Command, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let command = match args.subcommand()?.as_deref() {
+        // `bench-day` needs a day, `bench-all` is left to reject one as an unexpected argument
+        Some(command @ ("bench-day" | "bench-all")) => Command::Bench(BenchArgs {
+            year: args.opt_value_from_str(["-y", "--year"])?,
+            runs: args.opt_value_from_str(["-n", "--runs"])?,
+            save_baseline: args.contains("--save-baseline"),
+            compare: args.contains("--compare"),
+            threshold: args
+                .opt_value_from_str(["-t", "--threshold"])?
+                .unwrap_or(10_f64),
+            day: match command {
+                "bench-day" => Some(args.free_from_str()?),
+                _ => None,
+            },
+        }),
+        Some("verify") => Command::Verify {
+            year: args.opt_value_from_str(["-y", "--year"])?,
+            day: args.opt_free_from_str()?,
+        },
+        Some("submit") => Command::Submit {
+            year: args.opt_value_from_str(["-y", "--year"])?,
+            day: args.free_from_str()?,
+            part: args.free_from_str()?,
+            answer: args.opt_free_from_str()?,
+        },
+        Some(other) => {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: format!("unknown command \"{}\"", other),
+            })
+        }
+        None => Command::All(AllArgs {
+            year: args.opt_value_from_str(["-y", "--year"])?,
+            timeout: args
+                .opt_value_from_fn("--timeout", parse_timeout)?
+                .unwrap_or(Duration::from_secs_f64(DEFAULT_TIMEOUT_SECS)),
+            format: args
+                .opt_value_from_str(["-f", "--format"])?
+                .unwrap_or(Format::Text),
+            jobs: match args.opt_value_from_str(["-j", "--jobs"])? {
+                // 0 means one job per core
+                Some(0) => thread::available_parallelism().map_or(1, |jobs| jobs.get()),
+                Some(jobs) => jobs,
+                None => 1,
+            },
+            days: args.opt_value_from_fn(["-d", "--days"], advent_of_code::parse_days)?,
+            part: args.opt_value_from_fn(["-p", "--part"], advent_of_code::parse_part)?,
+            only_solved: args.contains("--only-solved"),
+        }),
+    };
+
+    let remaining = args.finish();
+    if !remaining.is_empty() {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unexpected arguments: {:?}", remaining),
+        });
+    }
+    Ok(command)
+}
+
+/// The (year, day) pairs to run: `day` of `year` (or the latest year solving it) if a day is
+/// given, otherwise every registered solution, limited to `year` if one is given.
+fn select_days(registry: &Registry, year: Option<u16>, day: Option<u8>) -> Vec<(u16, u8)> {
+    match day {
+        Some(day) => vec![(year.unwrap_or_else(|| registry.year_for_day(day)), day)],
+        None => registry
+            .iter()
+            .map(|solution| (solution.year(), solution.day()))
+            .filter(|(y, _)| year.is_none_or(|year| year == *y))
+            .collect(),
+    }
+}
+
+fn run_day(
+    registry: &'static Registry,
+    year: u16,
+    day: u8,
+    parts: &[u8],
+    timeout: Duration,
+) -> Vec<PartResult> {
+    let solution = match registry.get(year, day) {
+        Some(solution) => solution,
+        None => {
+            return parts
+                .iter()
+                .map(|&part| PartResult::unsolved(year, day, part))
+                .collect()
+        }
+    };
+
+    let input: Arc<str> = match advent_of_code::try_read_input(year, day) {
+        Ok(input) => input.into(),
+        Err(error) => {
+            return parts
+                .iter()
+                .map(|&part| PartResult::failed(year, day, part, &error))
+                .collect()
+        }
+    };
+
+    parts
+        .iter()
+        .map(|&part| {
+            PartResult::time_isolated(
+                year,
+                day,
+                part,
+                move |input| solution.solve_part(part, input),
+                input.clone(),
+                timeout,
+            )
+        })
+        .collect()
+}
 
-            let mut args = vec!["run", "--bin", &day];
-            if cfg!(not(debug_assertions)) {
-                args.push("--release");
+/// The parts of a day the all-days runner should run, honouring `--part` and `--only-solved`.
+fn select_parts(year: u16, day: u8, args: &AllArgs) -> Vec<u8> {
+    let answers = if args.only_solved {
+        Answers::load(&answers::answers_path(year, day)).unwrap_or_default()
+    } else {
+        Answers::default()
+    };
+    (1..=2)
+        .filter(|&part| args.part.is_none_or(|selected| selected == part))
+        .filter(|&part| !args.only_solved || answers.get(part).is_some())
+        .collect()
+}
+
+/// Worker threads used for `jobs` requested jobs: never more than there are days to run.
+fn worker_count(jobs: usize, days: usize) -> usize {
+    jobs.clamp(1, days.max(1))
+}
+
+/// Runs the given parts of `days` on `jobs` worker threads, passing each day's results to
+/// `on_day` in the order of `days` as soon as it and all days before it have finished.
+fn run_days(
+    registry: &'static Registry,
+    days: &[(u16, u8, Vec<u8>)],
+    jobs: usize,
+    timeout: Duration,
+    mut on_day: impl FnMut(u16, u8, Vec<PartResult>),
+) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..worker_count(jobs, days.len()) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some((year, day, parts)) = days.get(index) else {
+                    break;
+                };
+                if sender
+                    .send((index, run_day(registry, *year, *day, parts, timeout)))
+                    .is_err()
+                {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut finished = BTreeMap::new();
+        let mut next_index = 0;
+        for (index, day_results) in receiver {
+            finished.insert(index, day_results);
+            while let Some(day_results) = finished.remove(&next_index) {
+                let (year, day, _) = days[next_index];
+                on_day(year, day, day_results);
+                next_index += 1;
             }
+        }
+    });
+}
 
-            let cmd = Command::new("cargo").args(&args).output().unwrap();
+fn run_all(registry: &'static Registry, args: &AllArgs) {
+    let years = match args.year {
+        Some(year) => vec![year],
+        None if registry.years().is_empty() => vec![advent_of_code::default_year()],
+        None => registry.years(),
+    };
+    let day_filter = args.days.clone().unwrap_or_else(|| (1..=25).collect());
+    // days without a solver are skipped rather than reported as unsolved
+    let days: Vec<(u16, u8, Vec<u8>)> = years
+        .into_iter()
+        .flat_map(|year| day_filter.iter().map(move |&day| (year, day)))
+        .filter(|&(year, day)| registry.get(year, day).is_some())
+        .map(|(year, day)| (year, day, select_parts(year, day, args)))
+        .filter(|(_, _, parts)| !parts.is_empty())
+        .collect();
+    let mut results = Vec::new();
+    let workers = worker_count(args.jobs, days.len());
 
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
+    let wall_clock = Instant::now();
+    run_days(
+        registry,
+        &days,
+        args.jobs,
+        args.timeout,
+        |year, day, day_results| {
+            if args.format == Format::Text {
+                println!("---------------");
+                println!("{}| {} Day {:02} |{}", ANSI_BOLD, year, day, ANSI_RESET);
+                println!("---------------");
+                day_results.iter().for_each(advent_of_code::print_result);
+            }
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+            results.extend(day_results);
+        },
+    );
+    let wall_clock = wall_clock.elapsed();
 
+    match args.format {
+        Format::Text => {
+            let total: Duration = results
+                .iter()
+                .filter(|result| result.is_solved())
+                .map(|result| result.elapsed)
+                .sum();
+            let count = |status| {
+                results
+                    .iter()
+                    .filter(|result| result.status() == status)
+                    .count()
+            };
             println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+                "{}Total:{} {}{:.2}ms{} (wall clock {:.2}ms, {} {})",
+                ANSI_BOLD,
+                ANSI_RESET,
+                ANSI_ITALIC,
+                total.as_secs_f64() * 1000_f64,
+                ANSI_RESET,
+                wall_clock.as_secs_f64() * 1000_f64,
+                workers,
+                if workers == 1 { "job" } else { "jobs" }
+            );
+            println!(
+                "{} solved, {} unsolved, {} failed, {} panicked, {} timed out",
+                count(Status::Solved),
+                count(Status::Unsolved),
+                count(Status::Error),
+                count(Status::Panicked),
+                count(Status::TimedOut)
+            );
+        }
+        Format::Json => print!("{}", report::to_json(&results)),
+        Format::Csv => print!("{}", report::to_csv(&results)),
+    }
+}
+
+/// Benchmarks the selected days, returning whether any part regressed against the baseline.
+fn run_bench(registry: &Registry, args: &BenchArgs) -> bool {
+    let mut options = BenchOptions::default();
+    if let Some(runs) = args.runs {
+        options.min_runs = runs.max(1);
+        options.max_runs = runs.max(1);
+    }
+
+    let mut baselines: BTreeMap<u16, Baseline> = BTreeMap::new();
+    let mut regressed = false;
+
+    for (year, day) in select_days(registry, args.year, args.day) {
+        let baseline = match baselines.entry(year) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let baseline_path = bench::baseline_path(year);
+                match Baseline::load(&baseline_path) {
+                    Ok(baseline) => entry.insert(baseline),
+                    Err(e) => {
+                        eprintln!(
+                            "Failed to read baseline \"{}\": {}",
+                            baseline_path.display(),
+                            e
+                        );
+                        process::exit(1);
+                    }
                 }
+            }
+        };
+
+        println!("{}| {} Day {:02} |{}", ANSI_BOLD, year, day, ANSI_RESET);
+
+        let solution = match registry.get(year, day) {
+            Some(solution) => solution,
+            None => {
+                println!("Not solved.");
+                continue;
+            }
+        };
+        let input = match advent_of_code::try_read_input(year, day) {
+            Ok(input) => input,
+            Err(error) => {
+                println!("error: {}", error);
+                continue;
+            }
+        };
+
+        for part in 1..=2 {
+            print!("Part {}: ", part);
+            let stats =
+                match bench::bench(|input| solution.solve_part(part, input), &input, &options) {
+                    Ok(Some(stats)) => stats,
+                    Ok(None) => {
+                        println!("not solved.");
+                        continue;
+                    }
+                    Err(error) => {
+                        println!("error: {}", error);
+                        continue;
+                    }
+                };
+            println!(
+                "min {:.2?}, median {:.2?}, mean {:.2?} ± {:.2?} {}({} runs, {}){}",
+                stats.min,
+                stats.median,
+                stats.mean,
+                stats.stddev,
+                ANSI_ITALIC,
+                stats.runs,
+                bench::format_throughput(stats.throughput()),
+                ANSI_RESET
             );
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+            if args.compare {
+                match baseline.get(day, part) {
+                    Some(previous) => {
+                        let change = bench::change_percent(previous, stats.median);
+                        let is_regression = change > args.threshold;
+                        regressed |= is_regression;
+                        println!(
+                            "        {:+.1}% vs. baseline median {:.2?}{}",
+                            change,
+                            previous,
+                            if is_regression {
+                                format!(" {}(regression){}", ANSI_BOLD, ANSI_RESET)
+                            } else {
+                                String::new()
+                            }
+                        );
+                    }
+                    None => println!("        no baseline recorded."),
+                }
             }
-        })
-        .sum();
 
+            if args.save_baseline {
+                baseline.insert(day, part, stats.median);
+            }
+        }
+    }
+
+    if args.save_baseline {
+        for (year, baseline) in baselines.iter() {
+            let baseline_path = bench::baseline_path(*year);
+            match baseline.save(&baseline_path) {
+                Ok(_) => println!("🎄 Saved baseline to \"{}\".", baseline_path.display()),
+                Err(e) => {
+                    eprintln!("Failed to save baseline: {}", e);
+                    process::exit(1);
+                }
+            }
+        }
+    }
+
+    regressed
+}
+
+/// Checks the selected days against `src/YYYY/answers`, returning whether every recorded answer
+/// matched.
+fn run_verify(registry: &Registry, year: Option<u16>, day: Option<u8>) -> bool {
+    let mut passed = true;
+
+    for (year, day) in select_days(registry, year, day) {
+        let solution = match registry.get(year, day) {
+            Some(solution) => solution,
+            None => {
+                println!("{} Day {:02}: not solved.", year, day);
+                continue;
+            }
+        };
+        let answers_path = answers::answers_path(year, day);
+        let answers = match Answers::load(&answers_path) {
+            Ok(answers) => answers,
+            Err(e) => {
+                println!(
+                    "{} Day {:02}: error: could not read answers: {}",
+                    year, day, e
+                );
+                passed = false;
+                continue;
+            }
+        };
+        let input = match advent_of_code::try_read_input(year, day) {
+            Ok(input) => input,
+            Err(error) => {
+                println!("{} Day {:02}: error: {}", year, day, error);
+                // recorded answers can't be checked without the input
+                if answers != Answers::default() {
+                    passed = false;
+                }
+                continue;
+            }
+        };
+
+        for part in 1..=2 {
+            let expected = answers.get(part);
+            let verdict = match expected {
+                Some(_) => match solution.solve_part(part, &input) {
+                    Ok(actual) => Verdict::check(expected, actual.as_deref()),
+                    Err(error) => Verdict::Error(error),
+                },
+                None => Verdict::Missing,
+            };
+            if matches!(verdict, Verdict::Fail { .. } | Verdict::Error(_)) {
+                passed = false;
+            }
+            println!("{} Day {:02} part {}: {}", year, day, part, verdict);
+        }
+    }
+
+    passed
+}
+
+/// Submits an answer, computing it from the real input if not given, and records the outcome.
+/// Returns whether the answer was accepted (or was already known to be right).
+fn run_submit(registry: &Registry, year: u16, day: u8, part: u8, answer: Option<String>) -> bool {
+    if !(1..=2).contains(&part) {
+        eprintln!("Part must be 1 or 2, got {}.", part);
+        return false;
+    }
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solution = match registry.get(year, day) {
+                Some(solution) => solution,
+                None => {
+                    eprintln!(
+                        "{} Day {:02} is not solved, pass an answer explicitly.",
+                        year, day
+                    );
+                    return false;
+                }
+            };
+            let input = match advent_of_code::try_read_input(year, day) {
+                Ok(input) => input,
+                Err(error) => {
+                    eprintln!("error: {}", error);
+                    return false;
+                }
+            };
+            match solution.solve_part(part, &input) {
+                Ok(Some(answer)) => answer,
+                Ok(None) => {
+                    eprintln!("{} Day {:02} part {} is not solved.", year, day, part);
+                    return false;
+                }
+                Err(error) => {
+                    eprintln!("error: {}", error);
+                    return false;
+                }
+            }
+        }
+    };
+
+    let answers_path = answers::answers_path(year, day);
+    let wrong_path = answers::wrong_guesses_path(year, day);
+    let mut answers = match Answers::load(&answers_path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", answers_path.display(), e);
+            return false;
+        }
+    };
+    let mut wrong = match WrongGuesses::load(&wrong_path) {
+        Ok(wrong) => wrong,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", wrong_path.display(), e);
+            return false;
+        }
+    };
+
+    if answers.get(part) == Some(answer.as_str()) {
+        println!(
+            "🎄 {} is already recorded as the answer, not submitting.",
+            answer
+        );
+        return true;
+    }
+    if let Some(note) = wrong.find(part, &answer) {
+        eprintln!(
+            "{} was already rejected ({}), not submitting.",
+            answer, note
+        );
+        return false;
+    }
+
+    let client = match AocClient::from_env(Some(year)) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return false;
+        }
+    };
     println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+        "Submitting {} for {} day {:02} part {}...",
+        answer, year, day, part
     );
+    let outcome = match client.submit(day, part, &answer) {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return false;
+        }
+    };
+
+    let saved = if outcome == Submission::Correct {
+        println!("🎄 That's the right answer!");
+        answers.set(part, &answer);
+        answers.save(&answers_path).map(|_| &answers_path)
+    } else if outcome.is_wrong() {
+        println!("That's not the right answer: {}.", outcome);
+        wrong.record(part, &answer, &outcome.to_string());
+        wrong.save(&wrong_path).map(|_| &wrong_path)
+    } else {
+        println!("Answer was not checked: {}.", outcome);
+        return false;
+    };
+
+    match saved {
+        Ok(path) => println!("🎄 Recorded outcome in \"{}\".", path.display()),
+        Err(e) => eprintln!("Failed to record outcome: {}", e),
+    }
+
+    outcome == Submission::Correct
+}
+
+fn main() {
+    let command = match parse_args() {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    // lives for the whole run, so solvers can be moved onto their own threads
+    let registry: &'static Registry = Box::leak(Box::new(solutions::registry()));
+
+    match command {
+        Command::All(args) => {
+            advent_of_code::silence_solver_panics();
+            run_all(registry, &args)
+        }
+        Command::Bench(args) => {
+            if run_bench(registry, &args) {
+                eprintln!(
+                    "Performance regressed by more than {}% against the baseline.",
+                    args.threshold
+                );
+                process::exit(1);
+            }
+        }
+        Command::Verify { year, day } => {
+            if !run_verify(registry, year, day) {
+                process::exit(1);
+            }
+        }
+        Command::Submit {
+            day,
+            part,
+            answer,
+            year,
+        } => {
+            let year = year.unwrap_or_else(|| registry.year_for_day(day));
+            if !run_submit(registry, year, day, part, answer) {
+                process::exit(1);
+            }
+        }
+    }
 }
@@ -0,0 +1,131 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * Terminal rendering for the puzzle markdown written by `cargo download`, used by `cargo read`.
+ * Only covers what puzzle pages contain: headings, code blocks, lists, emphasis, code and links.
+ */
+use crate::{ANSI_BOLD, ANSI_DIM, ANSI_RESET, ANSI_UNDERLINE};
+
+/// Renders `markdown` with ANSI styles for display in a terminal.
+pub fn render(markdown: &str) -> String {
+    let mut rendered = String::new();
+    let mut in_code_block = false;
+    let mut lines = markdown.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            rendered.push_str(&format!("    {}{}{}\n", ANSI_DIM, line, ANSI_RESET));
+        } else if let Some(heading) = line.strip_prefix('#') {
+            let heading = heading.trim_start_matches('#').trim();
+            rendered.push_str(&render_heading(heading));
+        } else if lines
+            .peek()
+            .is_some_and(|next| !next.is_empty() && next.chars().all(|c| c == '-' || c == '='))
+            && !line.is_empty()
+        {
+            // setext heading, underlined by the next line
+            lines.next();
+            rendered.push_str(&render_heading(line));
+        } else if let Some(item) = line.strip_prefix("* ") {
+            rendered.push_str(&format!("  • {}\n", render_inline(item)));
+        } else {
+            rendered.push_str(&render_inline(line));
+            rendered.push('\n');
+        }
+    }
+
+    rendered
+}
+
+fn render_heading(heading: &str) -> String {
+    let heading = heading.strip_prefix('\\').unwrap_or(heading);
+    format!("{}{}{}\n", ANSI_BOLD, heading, ANSI_RESET)
+}
+
+/// Styles `*emphasis*`, `` `code` `` and `[links](href)`, and unescapes `\*`.
+fn render_inline(text: &str) -> String {
+    let mut rendered = String::new();
+    let mut chars = text.chars().peekable();
+    let mut emphasis = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek().is_some() => rendered.push(chars.next().unwrap()),
+            '`' => {
+                let code: String = chars.by_ref().take_while(|&c| c != '`').collect();
+                // emphasized code, e.g. `*7*`, is how puzzles highlight example answers
+                match code.strip_prefix('*').and_then(|c| c.strip_suffix('*')) {
+                    Some(code) if !code.is_empty() => rendered
+                        .push_str(&format!("{}{}{}{}", ANSI_BOLD, ANSI_DIM, code, ANSI_RESET)),
+                    _ => rendered.push_str(&format!("{}{}{}", ANSI_DIM, code, ANSI_RESET)),
+                }
+                if emphasis {
+                    rendered.push_str(ANSI_BOLD);
+                }
+            }
+            '*' => {
+                emphasis = !emphasis;
+                rendered.push_str(if emphasis { ANSI_BOLD } else { ANSI_RESET });
+            }
+            '[' => {
+                let label: String = chars.by_ref().take_while(|&c| c != ']').collect();
+                if chars.peek() == Some(&'(') {
+                    chars.by_ref().take_while(|&c| c != ')').for_each(drop);
+                }
+                rendered.push_str(&format!("{}{}{}", ANSI_UNDERLINE, label, ANSI_RESET));
+                if emphasis {
+                    rendered.push_str(ANSI_BOLD);
+                }
+            }
+            c => rendered.push(c),
+        }
+    }
+
+    if emphasis {
+        rendered.push_str(ANSI_RESET);
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_headings_and_code_blocks() {
+        let markdown = "\\--- Day 6: Tuning Trouble ---\n----------\n\n```\nmjq*j*\n```\n";
+        assert_eq!(
+            render(markdown),
+            format!(
+                "{b}--- Day 6: Tuning Trouble ---{r}\n\n    {d}mjq*j*{r}\n",
+                b = ANSI_BOLD,
+                d = ANSI_DIM,
+                r = ANSI_RESET
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_inline() {
+        assert_eq!(
+            render("* after `*5*`, see [this](/2016/day/6) \\*\n"),
+            format!(
+                "  • after {b}{d}5{r}, see {u}this{r} *\n",
+                b = ANSI_BOLD,
+                d = ANSI_DIM,
+                u = ANSI_UNDERLINE,
+                r = ANSI_RESET
+            )
+        );
+        assert_eq!(
+            render("the *star* fruit\n"),
+            format!("the {}star{} fruit\n", ANSI_BOLD, ANSI_RESET)
+        );
+    }
+}
@@ -0,0 +1,175 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * Machine-readable output for the all-days runner (`cargo all --format json|csv`).
+ */
+use crate::PartResult;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format \"{}\", expected one of: text, json, csv",
+                s
+            )),
+        }
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn json_option(value: &Option<String>) -> String {
+    match value {
+        Some(value) => json_string(value),
+        None => "null".to_string(),
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// One JSON object per day/part, as an array with one record per line.
+pub fn to_json(results: &[PartResult]) -> String {
+    let records: Vec<String> = results
+        .iter()
+        .map(|result| {
+            format!(
+                "  {{\"year\": {}, \"day\": {}, \"part\": {}, \"status\": {}, \"answer\": {}, \"elapsed_ns\": {}, \"error\": {}}}",
+                result.year,
+                result.day,
+                result.part,
+                json_string(&result.status().to_string()),
+                json_option(&result.answer),
+                result.elapsed.as_nanos(),
+                json_option(&result.error.as_ref().map(|error| error.to_string())),
+            )
+        })
+        .collect();
+
+    if records.is_empty() {
+        "[]\n".to_string()
+    } else {
+        format!("[\n{}\n]\n", records.join(",\n"))
+    }
+}
+
+/// One CSV row per day/part, preceded by a header row.
+pub fn to_csv(results: &[PartResult]) -> String {
+    let mut csv = String::from("year,day,part,status,answer,elapsed_ns,error\n");
+    for result in results {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            result.year,
+            result.day,
+            result.part,
+            result.status(),
+            csv_field(result.answer.as_deref().unwrap_or_default()),
+            result.elapsed.as_nanos(),
+            csv_field(
+                &result
+                    .error
+                    .as_ref()
+                    .map(|error| error.to_string())
+                    .unwrap_or_default()
+            ),
+        ));
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn results() -> Vec<PartResult> {
+        vec![
+            PartResult {
+                year: 2022,
+                day: 5,
+                part: 1,
+                answer: Some("CMZ".to_string()),
+                elapsed: Duration::from_nanos(1500),
+                error: None,
+            },
+            PartResult::unsolved(2022, 5, 2),
+            PartResult::failed(
+                2022,
+                6,
+                1,
+                "no input file \"src/2022/inputs/06.txt\", try `cargo download 6`",
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert_eq!("text".parse(), Ok(Format::Text));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            to_json(&results()),
+            concat!(
+                "[\n",
+                "  {\"year\": 2022, \"day\": 5, \"part\": 1, \"status\": \"solved\", \"answer\": \"CMZ\", \"elapsed_ns\": 1500, \"error\": null},\n",
+                "  {\"year\": 2022, \"day\": 5, \"part\": 2, \"status\": \"unsolved\", \"answer\": null, \"elapsed_ns\": 0, \"error\": null},\n",
+                "  {\"year\": 2022, \"day\": 6, \"part\": 1, \"status\": \"error\", \"answer\": null, \"elapsed_ns\": 0, \"error\": \"no input file \\\"src/2022/inputs/06.txt\\\", try `cargo download 6`\"}\n",
+                "]\n"
+            )
+        );
+        assert_eq!(to_json(&[]), "[]\n");
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&results()),
+            concat!(
+                "year,day,part,status,answer,elapsed_ns,error\n",
+                "2022,5,1,solved,CMZ,1500,\n",
+                "2022,5,2,unsolved,,0,\n",
+                "2022,6,1,error,,0,\"no input file \"\"src/2022/inputs/06.txt\"\", try `cargo download 6`\"\n",
+            )
+        );
+    }
+}